@@ -0,0 +1,251 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::helpers::ocr::{self, OcrError};
+
+/// The answer to one part, as returned by a solution, recorded in `answers/<year>/NN.toml` and submitted.
+/// Recorded answers are read with `Answer::parse`, so `24000`, `"24000"` and `" 24000\n"` are the same answer.
+/// Strings returned by solutions are kept as they are, so `"0123"` stays `Text`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// Any integer answer, whatever the width of the type it was computed in.
+    Int(i128),
+    Text(String),
+    /// Letters drawn over several lines, e.g. by a simulated screen. Trailing spaces and blank lines are ignored.
+    Art(String),
+}
+
+impl Answer {
+    /// Reads a recorded answer: integers written the way `Int` displays them become `Int`, anything
+    /// spanning lines `Art`, the rest trimmed `Text`. `"0123"` and `"+5"` stay `Text`.
+    pub fn parse(text: &str) -> Self {
+        let trimmed = text.trim();
+        if let Ok(value) = trimmed.parse::<i128>() {
+            if value.to_string() == trimmed {
+                return Answer::Int(value);
+            }
+        }
+        match trimmed.contains('\n') {
+            true => Answer::art(text),
+            false => Answer::Text(trimmed.to_string()),
+        }
+    }
+
+    /// Drawn letters, without the trailing spaces and the blank lines around them.
+    pub fn art(text: &str) -> Self {
+        let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+        let first = lines
+            .iter()
+            .position(|line| !line.is_empty())
+            .unwrap_or(lines.len());
+        let last = lines
+            .iter()
+            .rposition(|line| !line.is_empty())
+            .map_or(first, |last| last + 1);
+        Answer::Art(lines[first..last].join("\n"))
+    }
+
+    /// Whether both are the same answer. Only drawings are normalized first, see `Answer::art`,
+    /// so `Int(42)` and `Text("42")` differ.
+    pub fn matches(&self, other: &Answer) -> bool {
+        match (self, other) {
+            (Answer::Art(a), Answer::Art(b)) => Answer::art(a) == Answer::art(b),
+            _ => self == other,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i128> {
+        match self {
+            Answer::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn is_art(&self) -> bool {
+        matches!(self, Answer::Art(_))
+    }
+
+    /// The letters drawn by an `Art` answer, see `helpers::ocr`. Other answers are returned as they are.
+    pub fn read_letters(&self) -> Result<Answer, OcrError> {
+        match self {
+            Answer::Art(art) => Ok(Answer::Text(ocr::read(art)?)),
+            answer => Ok(answer.clone()),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(value) => f.write_fmt(format_args!("{}", value)),
+            Answer::Text(text) | Answer::Art(text) => f.write_str(text),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($int:ty),*) => {
+        $(impl From<$int> for Answer {
+            fn from(value: $int) -> Self {
+                Answer::Int(value.into())
+            }
+        })*
+    };
+}
+
+from_int!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Int(value as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Int(value as i128)
+    }
+}
+
+/// Values beyond `i128::MAX` are kept as their digits.
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        i128::try_from(value).map_or_else(|_| Answer::Text(value.to_string()), Answer::Int)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(value: char) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Integers that fit TOML are written as integers, everything else as a string.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.as_int().map(i64::try_from) {
+            Some(Ok(value)) => serializer.serialize_i64(value),
+            _ => serializer.collect_str(self),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+struct AnswerVisitor;
+
+impl Visitor<'_> for AnswerVisitor {
+    type Value = Answer;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an integer or a string")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Answer, E> {
+        Ok(value.into())
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Answer, E> {
+        Ok(value.into())
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<Answer, E> {
+        Ok(value.into())
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Answer, E> {
+        Ok(value.into())
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Answer, E> {
+        Ok(Answer::parse(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_compare_by_value() {
+        assert_eq!(Answer::from(24000_u32), Answer::parse(" 24000\n"));
+        assert_eq!(Answer::from(24000_u128), Answer::parse("24000"));
+        assert_eq!(Answer::from("0123"), Answer::Text("0123".to_string()));
+        assert_eq!(
+            Answer::from("24000".to_string()),
+            Answer::Text("24000".to_string())
+        );
+        assert_eq!(Answer::parse("0123"), Answer::Text("0123".to_string()));
+        assert_eq!(Answer::parse("+5"), Answer::Text("+5".to_string()));
+        assert_eq!(Answer::from(-3_i64), Answer::Int(-3));
+        assert_eq!(Answer::from("CMZ"), Answer::Text("CMZ".to_string()));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+        assert_eq!(
+            Answer::parse("\n#..#  \n#..#\n\n"),
+            Answer::art("#..#\n#..#")
+        );
+        assert_eq!(Answer::art("\n#..#  \n##.#\n").to_string(), "#..#\n##.#");
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(Answer::Int(42).matches(&Answer::from(42_u8)));
+        assert!(!Answer::Int(42).matches(&Answer::Text("42".to_string())));
+        assert!(!Answer::Text("42".to_string()).matches(&Answer::Int(42)));
+        assert!(Answer::Art("#.\n.#  \n".to_string()).matches(&Answer::art("#.\n.#")));
+        assert!(!Answer::Art("#.".to_string()).matches(&Answer::Text("#.".to_string())));
+    }
+
+    #[test]
+    fn test_serde() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Part {
+            answer: Answer,
+        }
+        let parsed: Part = toml::from_str("answer = 24000").unwrap();
+        assert_eq!(parsed.answer, Answer::Int(24000));
+        let parsed: Part = toml::from_str("answer = \" 24000\\n\"").unwrap();
+        assert_eq!(parsed.answer, Answer::Int(24000));
+        let parsed: Part = toml::from_str("answer = \"0123\"").unwrap();
+        assert_eq!(parsed.answer, Answer::Text("0123".to_string()));
+
+        let part = Part {
+            answer: Answer::Int(i128::from(i64::MAX) + 1),
+        };
+        assert_eq!(
+            toml::to_string(&part).unwrap(),
+            "answer = \"9223372036854775808\"\n"
+        );
+        assert_eq!(
+            serde_json::to_string(&Part {
+                answer: Answer::Int(7)
+            })
+            .unwrap(),
+            "{\"answer\":7}"
+        );
+        let json: Part = serde_json::from_str("{\"answer\":\"#.\\n.#\"}").unwrap();
+        assert_eq!(json.answer, Answer::art("#.\n.#"));
+    }
+}
@@ -0,0 +1,421 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::{error, fs, io};
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::solution::Solution;
+
+/// Known-correct answers for one day, stored in `answers/<year>/NN.toml`:
+///
+/// ```toml
+/// [input]
+/// part_one = 24000
+/// part_two = "some text"
+///
+/// [example]
+/// part_one = 157
+///
+/// [examples.2]
+/// part_two = 23
+/// ```
+///
+/// `[example]` belongs to `src/examples/<year>/NN.txt`, `[examples.<name>]` to `src/examples/<year>/NN-<name>.txt`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, skip_serializing_if = "Expected::is_empty")]
+    pub input: Expected,
+    #[serde(default, skip_serializing_if = "Expected::is_empty")]
+    pub example: Expected,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub examples: BTreeMap<String, Expected>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Expected {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<Answer>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part_one.as_ref(),
+            2 => self.part_two.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &Answer) {
+        match part {
+            1 => self.part_one = Some(answer.clone()),
+            2 => self.part_two = Some(answer.clone()),
+            _ => panic!("there is no part {part}"),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.part_one.is_none() && self.part_two.is_none()
+    }
+}
+
+impl Answers {
+    /// The answers recorded for the files in `src/<folder>`.
+    pub fn for_folder(&self, folder: &str) -> &Expected {
+        match folder {
+            "examples" => &self.example,
+            _ => &self.input,
+        }
+    }
+
+    /// The answers recorded for an example file; `None` is the unnamed `src/examples/<year>/NN.txt`.
+    pub fn for_example(&self, name: Option<&str>) -> Option<&Expected> {
+        match name {
+            None => Some(&self.example),
+            Some(name) => self.examples.get(name),
+        }
+    }
+}
+
+/// One of a day's example inputs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub name: Option<String>,
+    pub input: String,
+}
+
+impl fmt::Display for Example {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.name {
+            Some(name) => f.write_fmt(format_args!("example \"{name}\"")),
+            None => f.write_str("example"),
+        }
+    }
+}
+
+/// Collects `src/examples/<year>/NN.txt` and every `src/examples/<year>/NN-<name>.txt`, unnamed example first.
+pub fn examples(year: u16, day: u8) -> io::Result<Vec<Example>> {
+    let dir = crate::input::project_dir()
+        .join("src")
+        .join("examples")
+        .join(year.to_string());
+    let prefix = format!("{:02}", day);
+
+    let mut examples = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let stem = match (path.file_stem(), path.extension()) {
+            (Some(stem), Some(ext)) if ext == "txt" => stem.to_string_lossy().to_string(),
+            _ => continue,
+        };
+        let name = match stem.strip_prefix(&prefix) {
+            Some("") => None,
+            Some(rest) => match rest.strip_prefix('-') {
+                Some(name) if !name.is_empty() => Some(name.to_string()),
+                _ => continue,
+            },
+            None => continue,
+        };
+        examples.push(Example {
+            name,
+            input: fs::read_to_string(&path)?,
+        });
+    }
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+pub fn path(year: u16, day: u8) -> PathBuf {
+    crate::input::project_dir()
+        .join("answers")
+        .join(year.to_string())
+        .join(format!("{:02}.toml", day))
+}
+
+/// Reads `answers/<year>/NN.toml`. A missing file means no answer is known yet.
+pub fn load(year: u16, day: u8) -> Result<Answers, Box<dyn error::Error>> {
+    match fs::read_to_string(path(year, day)) {
+        Ok(text) => Ok(toml::from_str(&text)?),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Stores an accepted answer for the personal input in `answers/<year>/NN.toml`, keeping the other recorded answers.
+pub fn record(year: u16, day: u8, part: u8, answer: &Answer) -> Result<(), Box<dyn error::Error>> {
+    let mut answers = load(year, day)?;
+    answers.input.set(part, answer);
+    crate::input::write_atomic(&path(year, day), &toml::to_string(&answers)?)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn new(expected: Option<&Answer>, outcome: &Result<Answer, String>) -> Self {
+        match (expected, outcome) {
+            (None, _) => Verdict::Unknown,
+            (Some(expected), Ok(answer)) if expected.matches(answer) => Verdict::Pass,
+            // drawn letters are recorded as what was submitted, the letters they read as.
+            (Some(expected), Ok(answer))
+                if answer.read_letters().is_ok_and(|l| l.matches(expected)) =>
+            {
+                Verdict::Pass
+            }
+            (Some(expected), _) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail { .. } => "fail",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => f.write_str("PASS"),
+            Verdict::Fail { expected } => f.write_fmt(format_args!("FAIL (expected {expected})")),
+            Verdict::Unknown => f.write_str("UNKNOWN"),
+        }
+    }
+}
+
+fn run_part<S: Solution>(part: u8, input: &str) -> Result<Answer, String> {
+    match part {
+        1 => S::solve_part_one(input).map(Into::into),
+        2 => S::solve_part_two(input).map(Into::into),
+        _ => panic!("there is no part {part}"),
+    }
+    .map_err(|e| e.to_string())
+}
+
+/// Runs one part of `S` against `src/<folder>/<year>/NN.txt` and panics unless it matches the recorded answer.
+/// Parts without a recorded answer only need to return `Ok`. Used through `assert_answer!`.
+/// Personal inputs are not checked into git, so a missing or empty input file skips the check instead of failing.
+pub fn assert_answer<S: Solution>(part: u8, folder: &str) {
+    let input = match crate::try_read_file(folder, S::YEAR, S::DAY) {
+        Ok(input) => input,
+        Err(e) if e.is_absent() && folder != "examples" => {
+            println!("day {} part {part}: skipped, {e}", S::DAY);
+            return;
+        }
+        Err(e) => panic!("could not open input file: {}", e),
+    };
+    let outcome = run_part::<S>(part, &input);
+
+    let answers = load(S::YEAR, S::DAY).expect("could not read answers file");
+    match Verdict::new(answers.for_folder(folder).part(part), &outcome) {
+        Verdict::Fail { expected } => match outcome {
+            Ok(answer) => panic!(
+                "day {} part {part} answered {answer}, expected {expected}",
+                S::DAY
+            ),
+            Err(e) => panic!(
+                "day {} part {part} failed, expected {expected}:\n{e}",
+                S::DAY
+            ),
+        },
+        Verdict::Pass | Verdict::Unknown => {
+            if let Err(e) = outcome {
+                panic!("day {} part {part} failed:\n{e}", S::DAY);
+            }
+        }
+    }
+}
+
+/// Runs one part of `S` against every example that has a recorded answer for it and panics listing all mismatches.
+/// Used through `example_tests!`.
+pub fn assert_examples<S: Solution>(part: u8) {
+    let answers = load(S::YEAR, S::DAY).expect("could not read answers file");
+    let examples = examples(S::YEAR, S::DAY).expect("could not read example files");
+
+    let mut checked = 0;
+    let mut failures = vec![];
+    for example in &examples {
+        let expected = match answers
+            .for_example(example.name.as_deref())
+            .and_then(|expected| expected.part(part))
+        {
+            Some(expected) => expected,
+            None => continue,
+        };
+        checked += 1;
+        let outcome = run_part::<S>(part, &example.input);
+        match (Verdict::new(Some(expected), &outcome), outcome) {
+            (Verdict::Pass, _) => {}
+            (_, Ok(answer)) => {
+                failures.push(format!("{example}: answered {answer}, expected {expected}"))
+            }
+            (_, Err(e)) => failures.push(format!("{example}: failed, expected {expected}:\n{e}")),
+        }
+    }
+
+    if checked == 0 {
+        println!("day {} part {part}: no example answers recorded", S::DAY);
+    }
+    if !failures.is_empty() {
+        panic!(
+            "day {} part {part} failed {} of {checked} examples:\n{}",
+            S::DAY,
+            failures.len(),
+            failures.join("\n")
+        );
+    }
+}
+
+/// `assert_answer!(Day, 1, "inputs")` checks part 1 of `Day` against `answers/<year>/NN.toml`.
+#[macro_export]
+macro_rules! assert_answer {
+    ($solution:ty, $part:expr, $folder:expr) => {
+        advent_of_code::answers::assert_answer::<$solution>($part, $folder)
+    };
+}
+
+/// `example_tests!(Day)` generates a test per part that runs `Day` against all of its examples.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[test]
+        fn test_examples_part_one() {
+            advent_of_code::answers::assert_examples::<$solution>(1);
+        }
+
+        #[test]
+        fn test_examples_part_two() {
+            advent_of_code::answers::assert_examples::<$solution>(2);
+        }
+    };
+}
+
+/// `solution_tests!(Day)` generates the tests of a day: `example_tests!(Day)`, and a test per part that
+/// checks `Day` against the answers recorded for the personal input with `assert_answer!`.
+#[macro_export]
+macro_rules! solution_tests {
+    ($solution:ty) => {
+        advent_of_code::example_tests!($solution);
+
+        #[test]
+        fn test_part_one() {
+            advent_of_code::assert_answer!($solution, 1, "inputs");
+        }
+
+        #[test]
+        fn test_part_two() {
+            advent_of_code::assert_answer!($solution, 2, "inputs");
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers: Answers = toml::from_str(
+            "[input]\npart_one = 24000\npart_two = \"CMZ\"\n\n[example]\npart_two = 4\n",
+        )
+        .unwrap();
+        assert_eq!(answers.input.part(1), Some(&Answer::Int(24000)));
+        assert_eq!(answers.input.part(2), Some(&Answer::from("CMZ")));
+        assert_eq!(answers.for_folder("examples").part(1), None);
+        assert_eq!(
+            answers.for_folder("examples").part(2),
+            Some(&Answer::Int(4))
+        );
+        assert!(answers.for_example(Some("2")).is_none());
+    }
+
+    #[test]
+    fn test_named_example_answers() {
+        let answers: Answers =
+            toml::from_str("[example]\npart_one = 7\n\n[examples.2]\npart_one = 5\n").unwrap();
+        assert_eq!(
+            answers.for_example(None).unwrap().part(1),
+            Some(&Answer::Int(7))
+        );
+        assert_eq!(
+            answers.for_example(Some("2")).unwrap().part(1),
+            Some(&Answer::Int(5))
+        );
+    }
+
+    #[test]
+    fn test_write_answers() {
+        let mut answers: Answers = toml::from_str("[example]\npart_one = 7\n").unwrap();
+        answers.input.set(1, &Answer::Int(24000));
+        answers.input.set(2, &Answer::from("CMZ"));
+        assert_eq!(
+            toml::to_string(&answers).unwrap(),
+            "[input]\npart_one = 24000\npart_two = \"CMZ\"\n\n[example]\npart_one = 7\n"
+        );
+    }
+
+    #[test]
+    fn test_verdict() {
+        let four = Answer::Int(4);
+        assert_eq!(
+            Verdict::new(Some(&four), &Ok(Answer::from(4_u128))),
+            Verdict::Pass
+        );
+        assert_eq!(
+            Verdict::new(Some(&four), &Ok(Answer::Int(5))),
+            Verdict::Fail {
+                expected: four.clone()
+            }
+        );
+        assert_eq!(
+            Verdict::new(Some(&four), &Err("oops".to_string())),
+            Verdict::Fail {
+                expected: four.clone()
+            }
+        );
+        assert_eq!(
+            Verdict::new(
+                Some(&Answer::from("CMZ")),
+                &Ok(Answer::from("CMZ".to_string()))
+            ),
+            Verdict::Pass
+        );
+        assert_eq!(
+            Verdict::new(Some(&four), &Ok(Answer::from("4"))),
+            Verdict::Fail {
+                expected: four.clone()
+            }
+        );
+        assert_eq!(
+            Verdict::new(Some(&four), &Ok(Answer::from("04"))),
+            Verdict::Fail {
+                expected: four.clone()
+            }
+        );
+        assert_eq!(Verdict::new(None, &Ok(four)), Verdict::Unknown);
+
+        let h = Answer::art("#..#\n#..#\n####\n#..#\n#..#\n#..#");
+        assert_eq!(
+            Verdict::new(Some(&Answer::from("H")), &Ok(h.clone())),
+            Verdict::Pass
+        );
+        assert_eq!(Verdict::new(Some(&h), &Ok(h.clone())), Verdict::Pass);
+    }
+}
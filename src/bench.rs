@@ -0,0 +1,168 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::time::{Duration, Instant};
+
+use crate::{ANSI_BOLD, ANSI_RESET};
+
+/// How long and how often `--bench` runs a part.
+#[derive(Debug, Clone)]
+pub struct BenchConfig {
+    /// Time spent running the part before any sample is recorded. At least one warm-up run always happens.
+    pub warmup: Duration,
+    /// Upper bound on the number of recorded samples.
+    pub samples: usize,
+    /// Stop sampling once this much time was spent, even if fewer than `samples` were taken.
+    pub budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: Duration::from_millis(200),
+            samples: 100,
+            budget: Duration::from_secs(2),
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Reads `--bench`, `--samples <n>`, `--budget <ms>` and `--warmup <ms>`. Returns `None` without `--bench`.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        let enabled = args.contains("--bench");
+        let default = BenchConfig::default();
+        let samples = args.opt_value_from_str("--samples")?;
+        let budget = args.opt_value_from_str("--budget")?;
+        let warmup = args.opt_value_from_str("--warmup")?;
+        if !enabled {
+            return Ok(None);
+        }
+        Ok(Some(BenchConfig {
+            warmup: warmup.map(Duration::from_millis).unwrap_or(default.warmup),
+            samples: samples.unwrap_or(default.samples).max(1),
+            budget: budget.map(Duration::from_millis).unwrap_or(default.budget),
+        }))
+    }
+}
+
+/// Summary statistics over the samples of one benchmarked part.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Returns `None` for an empty sample set.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let variance = if n > 1 {
+            sorted
+                .iter()
+                .map(|s| (s.as_secs_f64() - mean.as_secs_f64()).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0_f64
+        };
+        // nearest-rank percentile.
+        let p95 = sorted[((n as f64 * 0.95).ceil() as usize).clamp(1, n) - 1];
+
+        Some(Stats {
+            samples: n,
+            min: sorted[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            p95,
+        })
+    }
+
+    pub fn print(&self) {
+        println!(
+            "{}{:>8} {:>10} {:>10} {:>10} {:>10} {:>10}{}",
+            ANSI_BOLD, "samples", "min", "median", "mean", "stddev", "p95", ANSI_RESET
+        );
+        println!(
+            "{:>8} {:>10.2?} {:>10.2?} {:>10.2?} {:>10.2?} {:>10.2?}",
+            self.samples, self.min, self.median, self.mean, self.stddev, self.p95
+        );
+    }
+}
+
+/// Warms `func` up, then samples it until either `config.samples` or `config.budget` is exhausted.
+pub fn bench<T, E>(func: impl Fn(&str) -> Result<T, E>, input: &str, config: &BenchConfig) -> Stats {
+    let warmup = Instant::now();
+    loop {
+        let _ = func(input);
+        if warmup.elapsed() >= config.warmup {
+            break;
+        }
+    }
+
+    let mut samples = Vec::with_capacity(config.samples);
+    let budget = Instant::now();
+    while samples.len() < config.samples && (samples.is_empty() || budget.elapsed() < config.budget)
+    {
+        let timer = Instant::now();
+        let result = func(input);
+        samples.push(timer.elapsed());
+        drop(result);
+    }
+
+    Stats::from_samples(&samples).expect("at least one sample is always taken")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_micros(*v)).collect()
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let stats = Stats::from_samples(&micros(&[5, 1, 4, 2, 3])).unwrap();
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.mean, Duration::from_micros(3));
+        assert_eq!(stats.p95, Duration::from_micros(5));
+        // sample stddev of 1..=5 is sqrt(2.5).
+        assert_eq!(stats.stddev.as_nanos(), 1581);
+
+        let even = Stats::from_samples(&micros(&[4, 1, 3, 2])).unwrap();
+        assert_eq!(even.median, Duration::from_nanos(2500));
+
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn test_bench_respects_sample_limit() {
+        let config = BenchConfig {
+            warmup: Duration::ZERO,
+            samples: 7,
+            budget: Duration::from_secs(60),
+        };
+        let stats = bench(|input: &str| Ok::<usize, ()>(input.len()), "abc", &config);
+        assert_eq!(stats.samples, 7);
+    }
+}
@@ -2,104 +2,102 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
-use std::{fs, process};
+use advent_of_code::client::Client;
+use advent_of_code::input::{self, project_dir};
+use advent_of_code::puzzle;
+use std::process;
 
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: Option<u16>,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
-        day: args.free_from_str()?,
         year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.free_from_str()?,
     })
 }
 
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
-    {
-        fs::remove_file(path);
-    }
-}
-
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
-}
-
 fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
-
     let args = match parse_args() {
         Ok(args) => args,
         Err(e) => {
             eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            process::exit(1);
+        }
+    };
+    let year = match args.year.or_else(input::default_year) {
+        Some(year) => year,
+        None => {
+            eprintln!(
+                "Need to specify a year, either with `--year` or the {} environment variable.",
+                input::YEAR_VAR
+            );
+            process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
-    }
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to set up the download: {}", e);
+            process::exit(1);
+        }
+    };
 
-    let mut cmd_args = vec![];
+    let day_padded = format!("{:02}", args.day);
+    let input_path = input::input_path("inputs", year, args.day);
+    let puzzle_path = format!("src/puzzles/{}/{}.md", year, day_padded);
 
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+    println!("Downloading input for day {}, {}...", args.day, year);
+    let written = client
+        .input(year, args.day)
+        .map_err(|e| e.to_string())
+        .and_then(|input| input::write_atomic(&input_path, &input).map_err(|e| e.to_string()));
+    match written {
+        Ok(_) => {
+            println!(
+                "🎄 Successfully wrote input to \"{}\".",
+                input_path.display()
+            );
+        }
+        Err(e) => {
+            eprintln!("could not download input: {}", e);
+            process::exit(1);
+        }
     }
 
-    cmd_args.append(&mut vec![
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
-
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
-
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
-            }
+    // the description includes part two once part one is solved, so it is refreshed on every download.
+    let written = client
+        .puzzle(year, args.day)
+        .map_err(|e| e.to_string())
+        .and_then(|page| {
+            input::write_atomic(&project_dir().join(&puzzle_path), &puzzle::from_html(&page))
+                .map_err(|e| e.to_string())
+        });
+    match written {
+        Ok(_) => {
+            println!("🎄 Successfully wrote puzzle description to \"{}\".", puzzle_path);
         }
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("could not download puzzle description: {}", e);
+            process::exit(1);
         }
     }
 
-    match fs::copy(&tmp_file_path, &input_path) {
-        Ok(_) => {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
+    match puzzle::write_example(year, args.day) {
+        Ok(true) => {
+            println!(
+                "🎄 Extracted the example into \"src/examples/{}/{}.txt\".",
+                year, day_padded
+            );
         }
+        Ok(false) => {}
         Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("could not extract the example: {}", e);
+            process::exit(1);
         }
     }
 }
@@ -0,0 +1,91 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::generate;
+use advent_of_code::input;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process;
+
+struct Args {
+    day: u8,
+    year: Option<u16>,
+    size: Option<usize>,
+    seed: u64,
+    output: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        size: args.opt_value_from_str("--size")?,
+        seed: args.opt_value_from_str("--seed")?.unwrap_or(0),
+        output: args.opt_value_from_str(["-o", "--output"])?,
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!(
+                "Failed to process arguments: {}. example: `cargo gen 8 --size 1000 --seed 1`",
+                e
+            );
+            process::exit(1);
+        }
+    };
+    let year = match args.year.or_else(input::default_year) {
+        Some(year) => year,
+        None => {
+            eprintln!(
+                "Need to specify a year, either with `--year` or the {} environment variable.",
+                input::YEAR_VAR
+            );
+            process::exit(1);
+        }
+    };
+    let generator = match generate::find(year, args.day) {
+        Some(generator) => generator,
+        None => {
+            eprintln!(
+                "There is no input generator for {} day {:02}.",
+                year, args.day
+            );
+            process::exit(1);
+        }
+    };
+
+    let size = args.size.unwrap_or(generator.default_size);
+    if size < generator.min_size {
+        eprintln!(
+            "The input for {} day {:02} needs a size of at least {} {}.",
+            year, args.day, generator.min_size, generator.unit
+        );
+        process::exit(1);
+    }
+    let generated = generator.generate(size, args.seed);
+    let written = match &args.output {
+        Some(path) => input::write_atomic(path, &generated),
+        None => io::stdout().lock().write_all(generated.as_bytes()),
+    };
+    match (written, &args.output) {
+        (Ok(_), Some(path)) => eprintln!(
+            "🎄 Generated {} {} for {} day {:02} with seed {} into \"{}\".",
+            size,
+            generator.unit,
+            year,
+            args.day,
+            args.seed,
+            path.display()
+        ),
+        (Ok(_), None) => {}
+        (Err(e), _) => {
+            eprintln!("Failed to write the generated input: {}", e);
+            process::exit(1);
+        }
+    }
+}
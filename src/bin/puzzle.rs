@@ -0,0 +1,58 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::input;
+use advent_of_code::puzzle;
+use std::{env, process};
+
+struct Args {
+    day: u8,
+    year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo puzzle 7 --year 2022`");
+            process::exit(1);
+        }
+    };
+    let year = match args.year.or_else(input::default_year) {
+        Some(year) => year,
+        None => {
+            eprintln!(
+                "Need to specify a year, either with `--year` or the {} environment variable.",
+                input::YEAR_VAR
+            );
+            process::exit(1);
+        }
+    };
+
+    let description = match puzzle::load(year, args.day) {
+        Ok(description) => description,
+        Err(e) => {
+            eprintln!("No puzzle description: {}", e);
+            eprintln!(
+                "🎄 Type `cargo download {} --year {}` to fetch it.",
+                args.day, year
+            );
+            process::exit(1);
+        }
+    };
+
+    let width = env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse().ok())
+        .unwrap_or(80);
+    print!("{}", puzzle::render(&description, width));
+}
@@ -0,0 +1,107 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::input::{self, project_dir};
+use advent_of_code::readme::{self, Row};
+use advent_of_code::{answers, history, submit};
+use std::path::PathBuf;
+use std::{fs, process};
+
+struct Args {
+    year: Option<u16>,
+    answers: bool,
+    history: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        answers: args.contains("--answers"),
+        history: args.opt_value_from_str("--history")?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    let year = match args.year.or_else(input::default_year) {
+        Some(year) => year,
+        None => {
+            eprintln!(
+                "Need to specify a year, either with `--year` or the {} environment variable.",
+                input::YEAR_VAR
+            );
+            process::exit(1);
+        }
+    };
+
+    let history_path = args.history.unwrap_or_else(history::default_path);
+    let loaded = history::load(&history_path)
+        .map_err(|e| format!("could not read \"{}\": {}", history_path.display(), e))
+        .and_then(|runs| {
+            let submissions = submit::load(&submit::default_path())
+                .map_err(|e| format!("could not read the submission history: {}", e))?;
+            Ok((runs, submissions))
+        });
+    let (runs, submissions) = match loaded {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("Failed to update README.md: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let profile = readme::profile(&runs, year).unwrap_or("release");
+    let mut rows = vec![];
+    for day in 1..=25 {
+        let answers = match answers::load(year, day) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("Failed to read answers for {} day {:02}: {}", year, day, e);
+                process::exit(1);
+            }
+        };
+        let row = Row::new(year, day, &answers, &submissions, &runs, profile);
+        let scaffolded = project_dir()
+            .join(format!("src/solutions/{}/{:02}.rs", year, day))
+            .exists();
+        if scaffolded || !row.is_empty() {
+            rows.push(row);
+        }
+    }
+
+    let readme_path = project_dir().join("README.md");
+    let updated = fs::read_to_string(&readme_path)
+        .map_err(|e| e.to_string())
+        .and_then(|text| {
+            readme::replace_table(&text, &readme::table(year, &rows, args.answers)).ok_or_else(
+                || {
+                    format!(
+                        "add the lines `{}` and `{}` where the table should go",
+                        readme::START_MARKER,
+                        readme::END_MARKER
+                    )
+                },
+            )
+        })
+        .and_then(|text| input::write_atomic(&readme_path, &text).map_err(|e| e.to_string()));
+    match updated {
+        Ok(_) => {
+            println!(
+                "🎄 Updated the table of {} days in \"README.md\".",
+                rows.len()
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to update README.md: {}", e);
+            process::exit(1);
+        }
+    }
+}
@@ -2,120 +2,69 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::{
-    fs::{File, OpenOptions},
-    io::Write,
-    process,
-};
-
-const MODULE_TEMPLATE: &str = r###"
-use advent_of_code::helpers::err;
-use std::error;
-
-pub fn part_one(input: &str) -> Result<u32, impl error::Error> {
-
-}
-
-pub fn part_two(input: &str) -> Result<u32, impl error::Error> {
-
-}
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+use advent_of_code::input;
+use advent_of_code::scaffold::{self, Status};
+use advent_of_code::template;
+use std::process;
+
+struct Args {
+    day: u8,
+    year: Option<u16>,
+    template: String,
+    dry_run: bool,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    macro_rules! get_input {
-        () => {
-            &advent_of_code::read_file("inputs", DAY)
-        };
-    }
-    #[test]
-    fn test_part_one() {
-        part_one(get_input!()).expect("First set of inputs resolves to a value.");
-    }
-
-    #[test]
-    fn test_part_two() {
-        part_two(get_input!()).expect("Second set of inputs resolves to a value.");
-    }
-}
-
-"###;
-
-fn parse_args() -> Result<u8, pico_args::Error> {
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
-}
-
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
-}
-
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        template: args
+            .opt_value_from_str(["-t", "--template"])?
+            .unwrap_or_else(|| template::DEFAULT_TEMPLATE.to_string()),
+        dry_run: args.contains(["-n", "--dry-run"]),
+        day: args.free_from_str()?,
+    })
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7 --year 2022`");
             process::exit(1);
         }
     };
-
-    let day_padded = format!("{:02}", day);
-
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
-
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
+    let (day, year) = match args.year.or_else(input::default_year) {
+        Some(year) => (args.day, year),
+        None => {
+            eprintln!(
+                "Need to specify a year, either with `--year` or the {} environment variable.",
+                input::YEAR_VAR
+            );
             process::exit(1);
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
+    // everything is worked out before anything is written, so a bad template leaves no half-scaffolded day behind.
+    let mut artifacts = match scaffold::plan(year, day, &args.template) {
+        Ok(artifacts) => artifacts,
         Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
-            process::exit(1);
-        }
-    }
-
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
+            eprintln!("Failed to scaffold day {}: {}", day, e);
             process::exit(1);
         }
+    };
+    if !args.dry_run {
+        scaffold::apply(&mut artifacts);
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
-            process::exit(1);
-        }
+    for artifact in &artifacts {
+        artifact.print(args.dry_run);
     }
-
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    if args.dry_run {
+        println!("🎄 Dry run, nothing was written.");
+    } else if artifacts.iter().any(|a| a.status == Status::Skipped) {
+        println!("🎄 Some files were skipped, see above.");
+    }
+    println!("🎄 Type `cargo solve {} {:02}` to run your solution.", year, day);
 }
@@ -0,0 +1,300 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::path::PathBuf;
+use std::time::Duration;
+use std::{env, error, fmt, fs, io};
+
+/// The session cookie itself. Takes precedence over the session file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Path of a file containing the session cookie, instead of `~/.adventofcode.session`.
+pub const SESSION_FILE_VAR: &str = "AOC_SESSION_FILE";
+/// Replaces `https://adventofcode.com`, e.g. with a local server in tests.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// Your repository or email, sent along in the user agent.
+pub const CONTACT_VAR: &str = "AOC_CONTACT";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The user agent without a contact.
+pub const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Identifies this crate and whoever runs it to the Advent of Code servers, as requested by
+/// their maintainer, e.g. `advent_of_code/0.8.0 (+https://github.com/you/aoc)`.
+pub fn user_agent(contact: Option<&str>) -> String {
+    match contact.map(str::trim).filter(|contact| !contact.is_empty()) {
+        Some(contact) => format!("{} (+{})", USER_AGENT, contact),
+        None => USER_AGENT.to_string(),
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// Neither `AOC_SESSION` nor a session file is set up.
+    NoSession(PathBuf),
+    /// The puzzle exists but is not unlocked yet, or the year/day is invalid.
+    NotFound(String),
+    /// Advent of Code answers with 400 for invalid or expired session cookies.
+    Unauthorized(String, u16),
+    Status(String, u16),
+    Transport(String, String),
+    Io(io::Error),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::NoSession(path) => f.write_fmt(format_args!(
+                "no session cookie, set {} or write it to \"{}\"",
+                SESSION_VAR,
+                path.display()
+            )),
+            ClientError::NotFound(url) => {
+                f.write_fmt(format_args!("{} is not available (yet)", url))
+            }
+            ClientError::Unauthorized(url, status) => f.write_fmt(format_args!(
+                "{} answered {}, the session cookie is probably invalid or expired",
+                url, status
+            )),
+            ClientError::Status(url, status) => {
+                f.write_fmt(format_args!("{} answered {}", url, status))
+            }
+            ClientError::Transport(url, e) => {
+                f.write_fmt(format_args!("could not reach {}: {}", url, e))
+            }
+            ClientError::Io(e) => f.write_fmt(format_args!("{}", e)),
+        }
+    }
+}
+
+impl error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ClientError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        ClientError::Io(e)
+    }
+}
+
+/// Talks to the Advent of Code website on behalf of one session.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, user_agent: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(user_agent)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Reads the session from `AOC_SESSION`, `AOC_SESSION_FILE` or `~/.adventofcode.session`,
+    /// the base URL from `AOC_BASE_URL` and the contact for the user agent from `AOC_CONTACT`.
+    pub fn from_env() -> Result<Self, ClientError> {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let agent = user_agent(env::var(CONTACT_VAR).ok().as_deref());
+        if let Ok(session) = env::var(SESSION_VAR) {
+            return Ok(Client::new(&base_url, &session, &agent));
+        }
+
+        let path = session_file();
+        match fs::read_to_string(&path) {
+            Ok(session) if !session.trim().is_empty() => {
+                Ok(Client::new(&base_url, &session, &agent))
+            }
+            Ok(_) => Err(ClientError::NoSession(path)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ClientError::NoSession(path)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    /// The puzzle page as HTML, with part two once part one is solved.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{}/day/{}", year, day))
+    }
+
+    /// Posts `answer` for one part and returns the HTML page with the verdict, see `submit::Outcome::parse`.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let level = part.to_string();
+        self.send(
+            "POST",
+            &format!("/{}/day/{}/answer", year, day),
+            Some(&[("level", &level), ("answer", answer)]),
+        )
+    }
+
+    fn get(&self, path: &str) -> Result<String, ClientError> {
+        self.send("GET", path, None)
+    }
+
+    fn send(
+        &self,
+        method: &str,
+        path: &str,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String, ClientError> {
+        let url = format!("{}{}", self.base_url, path);
+        let request = self
+            .agent
+            .request(method, &url)
+            .set("Cookie", &format!("session={}", self.session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(404, _)) => Err(ClientError::NotFound(url)),
+            Err(ureq::Error::Status(400, _)) => Err(ClientError::Unauthorized(url, 400)),
+            Err(ureq::Error::Status(status, _)) => Err(ClientError::Status(url, status)),
+            Err(ureq::Error::Transport(e)) => Err(ClientError::Transport(url, e.to_string())),
+        }
+    }
+}
+
+fn session_file() -> PathBuf {
+    if let Some(path) = env::var_os(SESSION_FILE_VAR) {
+        return PathBuf::from(path);
+    }
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .unwrap_or_default();
+    PathBuf::from(home).join(".adventofcode.session")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Answers a single request with `status` and `body`, and hands back the request head and body.
+    fn serve_once(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut head = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            let length = head
+                .lines()
+                .find_map(|line| {
+                    line.to_lowercase()
+                        .strip_prefix("content-length: ")?
+                        .parse()
+                        .ok()
+                })
+                .unwrap_or(0);
+            let mut request_body = vec![0; length];
+            reader.read_exact(&mut request_body).unwrap();
+            head.push_str(&String::from_utf8(request_body).unwrap());
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            head
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_fetches_input_with_session_and_user_agent() {
+        let (base_url, server) = serve_once("200 OK", "1\n2\n");
+        let agent = user_agent(Some(" me@example.com\n"));
+        let client = Client::new(&base_url, "abc123\n", &agent);
+
+        assert_eq!(client.input(2022, 8).unwrap(), "1\n2\n");
+        let head = server.join().unwrap().to_lowercase();
+        assert!(head.starts_with("get /2022/day/8/input http/1.1"));
+        assert!(head.contains("cookie: session=abc123\r\n"));
+        assert!(head.contains(&format!("user-agent: {} (+me@example.com)\r\n", USER_AGENT)));
+        assert_eq!(user_agent(Some("")), USER_AGENT);
+        assert_eq!(user_agent(None), USER_AGENT);
+    }
+
+    #[test]
+    fn test_submits_answer_as_form() {
+        let (base_url, server) = serve_once(
+            "200 OK",
+            "<article><p>That's the right answer!</p></article>",
+        );
+        let client = Client::new(&base_url, "abc123", USER_AGENT);
+
+        assert!(client
+            .submit(2022, 8, 2, "1 2")
+            .unwrap()
+            .contains("right answer"));
+        let request = server.join().unwrap().to_lowercase();
+        assert!(request.starts_with("post /2022/day/8/answer http/1.1"));
+        assert!(request.contains("content-type: application/x-www-form-urlencoded\r\n"));
+        assert!(request.ends_with("level=2&answer=1+2"));
+    }
+
+    #[test]
+    fn test_maps_error_statuses() {
+        let (base_url, server) = serve_once("404 Not Found", "");
+        let client = Client::new(&base_url, "abc123", USER_AGENT);
+        assert!(matches!(
+            client.puzzle(2022, 25),
+            Err(ClientError::NotFound(_))
+        ));
+        server.join().unwrap();
+
+        let (base_url, server) = serve_once("400 Bad Request", "");
+        let client = Client::new(&base_url, "expired", USER_AGENT);
+        assert!(matches!(
+            client.input(2022, 1),
+            Err(ClientError::Unauthorized(_, 400))
+        ));
+        server.join().unwrap();
+
+        let (base_url, server) = serve_once("500 Internal Server Error", "");
+        let client = Client::new(&base_url, "abc123", USER_AGENT);
+        assert!(matches!(
+            client.input(2022, 1),
+            Err(ClientError::Status(_, 500))
+        ));
+        server.join().unwrap();
+    }
+}
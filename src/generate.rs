@@ -0,0 +1,389 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ * Add a `Generator` to `GENERATORS` to make `cargo gen` produce inputs for another day.
+ */
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+
+/// A small seeded pseudo-random number generator (xorshift64*), so a seed gives the same input everywhere.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // splitmix64 spreads small seeds over the state, which must not be zero.
+        let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        Rng((z ^ (z >> 31)).max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {}..={}", start, end);
+        start + (self.next_u64() % (end - start + 1) as u64) as usize
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i));
+        }
+    }
+}
+
+/// Produces puzzle inputs of any size for one day, for stress-testing and benchmarking its solution.
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    /// What `size` counts, e.g. "elves".
+    pub unit: &'static str,
+    /// About the size of a real puzzle input.
+    pub default_size: usize,
+    /// The smallest size that gives a valid input.
+    pub min_size: usize,
+    generator: fn(usize, &mut Rng) -> String,
+}
+
+impl Generator {
+    /// The same `size` and `seed` always give the same input. Sizes below `min_size` are raised to it.
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        (self.generator)(size.max(self.min_size), &mut Rng::new(seed))
+    }
+}
+
+pub const GENERATORS: &[Generator] = &[
+    Generator {
+        year: 2022,
+        day: 1,
+        unit: "elves",
+        default_size: 250,
+        min_size: 1,
+        generator: calories,
+    },
+    Generator {
+        year: 2022,
+        day: 2,
+        unit: "rounds",
+        default_size: 2500,
+        min_size: 1,
+        generator: strategy_guide,
+    },
+    Generator {
+        year: 2022,
+        day: 3,
+        unit: "rucksacks",
+        default_size: 300,
+        min_size: 1,
+        generator: rucksacks,
+    },
+    Generator {
+        year: 2022,
+        day: 4,
+        unit: "pairs",
+        default_size: 1000,
+        min_size: 1,
+        generator: section_pairs,
+    },
+    Generator {
+        year: 2022,
+        day: 5,
+        unit: "moves",
+        default_size: 500,
+        min_size: 1,
+        generator: crate_moves,
+    },
+    Generator {
+        year: 2022,
+        day: 6,
+        unit: "characters",
+        default_size: 4096,
+        min_size: 14,
+        generator: datastream,
+    },
+    Generator {
+        year: 2022,
+        day: 7,
+        unit: "files and directories",
+        default_size: 400,
+        min_size: 1,
+        generator: terminal,
+    },
+    Generator {
+        year: 2022,
+        day: 8,
+        unit: "trees per side",
+        default_size: 99,
+        min_size: 1,
+        generator: forest,
+    },
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.year == year && g.day == day)
+}
+
+/// 2022 day 1: an inventory of up to 15 items per elf, elves separated by a blank line.
+fn calories(elves: usize, rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for elf in 0..elves {
+        if elf > 0 {
+            input.push('\n');
+        }
+        for _ in 0..rng.range(1..=15) {
+            writeln!(input, "{}", rng.range(1_000..=60_000)).unwrap();
+        }
+    }
+    input
+}
+
+/// 2022 day 2: a round per line, e.g. `A Y`.
+fn strategy_guide(rounds: usize, rng: &mut Rng) -> String {
+    let mut input = String::with_capacity(rounds * 4);
+    for _ in 0..rounds {
+        writeln!(
+            input,
+            "{} {}",
+            rng.pick(&['A', 'B', 'C']),
+            rng.pick(&['X', 'Y', 'Z'])
+        )
+        .unwrap();
+    }
+    input
+}
+
+/// 2022 day 3: rucksacks whose halves share exactly one item, in groups of three that share exactly one
+/// badge. The count is rounded up to whole groups.
+fn rucksacks(rucksacks: usize, rng: &mut Rng) -> String {
+    let items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut input = String::new();
+    for _ in 0..rucksacks.div_ceil(3) {
+        let mut items = items.clone();
+        rng.shuffle(&mut items);
+        let badge = items[51];
+        // each of the three gets 17 items of its own, so the badge is the only one they have in common.
+        for own in items[..51].chunks(17) {
+            let (shared, left_only, right_only) = (own[0], &own[1..9], &own[9..]);
+            let half = rng.range(4..=16);
+            let mut halves = [vec![shared], vec![shared]];
+            for _ in 1..half {
+                halves[0].push(*rng.pick(left_only));
+                halves[1].push(*rng.pick(right_only));
+            }
+            let side = rng.range(0..=1);
+            halves[side][rng.range(1..=half - 1)] = badge;
+            for half in &mut halves {
+                rng.shuffle(half);
+                input.extend(half.iter());
+            }
+            input.push('\n');
+        }
+    }
+    input
+}
+
+/// 2022 day 4: a pair of section ranges per line, e.g. `2-4,6-8`.
+fn section_pairs(pairs: usize, rng: &mut Rng) -> String {
+    let mut input = String::with_capacity(pairs * 12);
+    for _ in 0..pairs {
+        let [a, b, c, d] = [(); 4].map(|_| rng.range(1..=99));
+        writeln!(input, "{}-{},{}-{}", a.min(b), a.max(b), c.min(d), c.max(d)).unwrap();
+    }
+    input
+}
+
+/// 2022 day 5: nine stacks of crates drawn side by side, then moves that never empty a stack.
+fn crate_moves(moves: usize, rng: &mut Rng) -> String {
+    let letters: Vec<char> = ('A'..='Z').collect();
+    let mut stacks: Vec<Vec<char>> = (0..9)
+        .map(|_| (0..rng.range(2..=8)).map(|_| *rng.pick(&letters)).collect())
+        .collect();
+
+    let mut input = String::new();
+    let tallest = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    for level in (0..tallest).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| {
+                stack
+                    .get(level)
+                    .map_or("   ".to_string(), |c| format!("[{}]", c))
+            })
+            .collect();
+        writeln!(input, "{}", row.join(" ")).unwrap();
+    }
+    let labels: Vec<String> = (1..=stacks.len()).map(|i| format!(" {} ", i)).collect();
+    writeln!(input, "{}\n", labels.join(" ")).unwrap();
+
+    // there are more crates than stacks, so some stack always has one to spare.
+    for _ in 0..moves {
+        let sources: Vec<usize> = (0..stacks.len()).filter(|&i| stacks[i].len() > 1).collect();
+        let from = *rng.pick(&sources);
+        let to = (from + rng.range(1..=stacks.len() - 1)) % stacks.len();
+        let height = stacks[from].len();
+        let count = rng.range(1..=height - 1);
+        let moved = stacks[from].split_off(height - count);
+        stacks[to].extend(moved);
+        writeln!(input, "move {} from {} to {}", count, from + 1, to + 1).unwrap();
+    }
+    input
+}
+
+/// 2022 day 6: a datastream of three letters up to its last 14 characters, which all differ,
+/// so both markers are only found at the very end. Needs a `length` of at least 14.
+fn datastream(length: usize, rng: &mut Rng) -> String {
+    let mut marker: Vec<char> = ('a'..='z').collect();
+    rng.shuffle(&mut marker);
+    let mut input: String = (0..length - 14)
+        .map(|_| *rng.pick(&['x', 'y', 'z']))
+        .collect();
+    input.extend(marker.iter().filter(|c| !"xyz".contains(**c)).take(14));
+    input.push('\n');
+    input
+}
+
+/// 2022 day 7: the transcript of listing every directory of a random tree, depth first.
+/// File sizes add up to between 40 and 69 million, so part two has to delete something.
+fn terminal(entries: usize, rng: &mut Rng) -> String {
+    struct Node {
+        name: String,
+        size: Option<u64>,
+        children: Vec<usize>,
+    }
+    let mut nodes = vec![Node {
+        name: "/".to_string(),
+        size: None,
+        children: vec![],
+    }];
+    let mut directories = vec![0];
+    let mut names: Vec<HashSet<String>> = vec![HashSet::new()];
+    let letters: Vec<char> = ('a'..='z').collect();
+    for _ in 0..entries {
+        let parent = *rng.pick(&directories);
+        let is_file = rng.range(0..=9) < 7;
+        let mut name: String = (0..rng.range(1..=8)).map(|_| *rng.pick(&letters)).collect();
+        if is_file && rng.range(0..=1) == 0 {
+            name.push_str(rng.pick(&[".txt", ".dat", ".log", ".lst", ".ext"]));
+        }
+        while !names[parent].insert(name.clone()) {
+            name.push(*rng.pick(&letters));
+        }
+        let id = nodes.len();
+        nodes.push(Node {
+            name,
+            size: is_file.then_some(rng.range(1..=1_000) as u64),
+            children: vec![],
+        });
+        nodes[parent].children.push(id);
+        names.push(HashSet::new());
+        if !is_file {
+            directories.push(id);
+        }
+    }
+
+    // the sizes drawn above are weights, scaled to the total.
+    let total = rng.range(40_000_000..=69_000_000) as u64;
+    let weights: u64 = nodes.iter().filter_map(|node| node.size).sum();
+    let budget = total.saturating_sub(nodes.len() as u64);
+    for node in &mut nodes {
+        node.size = node.size.map(|weight| 1 + weight * budget / weights.max(1));
+    }
+
+    let mut input = "$ cd /\n".to_string();
+    let mut stack = vec![Some(0)];
+    while let Some(entry) = stack.pop() {
+        let Some(directory) = entry else {
+            input.push_str("$ cd ..\n");
+            continue;
+        };
+        if directory != 0 {
+            writeln!(input, "$ cd {}", nodes[directory].name).unwrap();
+        }
+        input.push_str("$ ls\n");
+        for &child in &nodes[directory].children {
+            match nodes[child].size {
+                Some(size) => writeln!(input, "{} {}", size, nodes[child].name).unwrap(),
+                None => writeln!(input, "dir {}", nodes[child].name).unwrap(),
+            }
+        }
+        // `None` climbs back up once the directory and everything below it is listed.
+        for &child in nodes[directory].children.iter().rev() {
+            if nodes[child].size.is_none() {
+                stack.push(None);
+                stack.push(Some(child));
+            }
+        }
+    }
+    input
+}
+
+/// 2022 day 8: a square forest that grows taller towards the middle, like the real ones.
+fn forest(side: usize, rng: &mut Rng) -> String {
+    let mut input = String::with_capacity(side * (side + 1));
+    for y in 0..side {
+        for x in 0..side {
+            let from_middle = x.abs_diff(side / 2).max(y.abs_diff(side / 2));
+            let hill = 9 - 9 * from_middle / (side / 2 + 1);
+            input.push(char::from(
+                b'0' + hill.saturating_sub(rng.range(0..=3)) as u8,
+            ));
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generators_are_reproducible() {
+        for generator in GENERATORS {
+            let input = generator.generate(30, 7);
+            assert_eq!(input, generator.generate(30, 7), "day {}", generator.day);
+            assert_ne!(input, generator.generate(30, 8), "day {}", generator.day);
+        }
+        assert_eq!(find(2022, 8).unwrap().generate(3, 1).lines().count(), 3);
+        assert_eq!(find(2022, 6).unwrap().generate(20, 1).trim_end().len(), 20);
+        assert_eq!(find(2022, 6).unwrap().generate(5, 1).trim_end().len(), 14);
+        assert!(find(2022, 25).is_none());
+    }
+
+    #[test]
+    fn test_rucksack_groups_share_one_badge() {
+        let input = rucksacks(301, &mut Rng::new(1));
+        let lines: Vec<&str> = input.lines().collect();
+        assert_eq!(lines.len(), 303);
+        let items = |text: &str| text.chars().collect::<HashSet<_>>();
+        for line in &lines {
+            let (left, right) = line.split_at(line.len() / 2);
+            assert_eq!(
+                items(left).intersection(&items(right)).count(),
+                1,
+                "{}",
+                line
+            );
+        }
+        for group in lines.chunks(3) {
+            let common: HashSet<char> = items(group[0])
+                .intersection(&items(group[1]))
+                .copied()
+                .collect();
+            assert_eq!(common.intersection(&items(group[2])).count(), 1);
+        }
+    }
+}
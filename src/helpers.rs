@@ -1,3 +1,7 @@
+pub mod grid;
+pub mod ocr;
+pub mod parse;
+
 pub mod err {
     use std::fmt;
     use std::error::Error;
@@ -7,11 +11,100 @@ pub mod err {
     pub struct TokenError {
         pub token: String,
         pub reason: String,
+        /// Where `token` is in the input, if it is known. See `TokenError::at`.
+        pub span: Option<Span>,
+    }
+
+    /// Where a token was found in the input. Line and column start at 1, the column counts characters.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Span {
+        pub offset: usize,
+        pub line: usize,
+        pub column: usize,
+        /// Characters to underline.
+        pub len: usize,
+        pub source_line: String,
+    }
+
+    /// Tokens and source lines longer than this are cut short in error messages.
+    const MAX_WIDTH: usize = 40;
+
+    impl Span {
+        /// Finds `token` in `input`. A slice of `input` is located exactly, anything else by its first occurrence.
+        pub fn locate(input: &str, token: &str) -> Option<Self> {
+            let start = input.as_ptr() as usize;
+            let at = token.as_ptr() as usize;
+            let offset = if at >= start && at + token.len() <= start + input.len() {
+                at - start
+            } else if !token.is_empty() {
+                input.find(token)?
+            } else {
+                return None;
+            };
+            let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+            Some(Span {
+                offset,
+                line: input[..offset].matches('\n').count() + 1,
+                column: input[line_start..offset].chars().count() + 1,
+                len: token.lines().next().unwrap_or_default().chars().count(),
+                source_line: input[line_start..].lines().next().unwrap_or_default().to_string(),
+            })
+        }
+    }
+
+    impl TokenError {
+        pub fn new(token: impl Into<String>, reason: impl Into<String>) -> Self {
+            TokenError { token: token.into(), reason: reason.into(), span: None }
+        }
+
+        /// An error about `token`, a slice of `input`, that points at where it is in the input.
+        pub fn at(input: &str, token: &str, reason: impl Into<String>) -> Self {
+            TokenError { token: token.to_string(), reason: reason.into(), span: Span::locate(input, token) }
+        }
+    }
+
+    fn shorten(text: &str, max: usize) -> String {
+        match text.char_indices().nth(max) {
+            Some((end, _)) => format!("{}…", &text[..end]),
+            None => text.to_string(),
+        }
+    }
+
+    impl fmt::Display for Span {
+        /// The source line with the token underlined, cut down to the part around the token.
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let chars: Vec<char> = self.source_line.chars().collect();
+            let column = (self.column - 1).min(chars.len());
+            let len = self.len.clamp(1, MAX_WIDTH);
+            let start = column.saturating_sub(MAX_WIDTH / 2);
+            let end = (column + len + MAX_WIDTH / 2).min(chars.len());
+            let prefix = if start > 0 { "…" } else { "" };
+            let suffix = if end < chars.len() { "…" } else { "" };
+
+            let gutter = " ".repeat(self.line.to_string().len());
+            f.write_fmt(format_args!(
+                "{} | {}{}{}\n{} | {}{}",
+                self.line,
+                prefix,
+                chars[start..end].iter().collect::<String>(),
+                suffix,
+                gutter,
+                " ".repeat(column - start + prefix.chars().count()),
+                "^".repeat(len)
+            ))
+        }
     }
 
     impl fmt::Display for TokenError {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            f.write_fmt(format_args!("Could not parse {} because {}", &self.token, &self.reason))
+            let token = shorten(&self.token, MAX_WIDTH);
+            match &self.span {
+                Some(span) => f.write_fmt(format_args!(
+                    "Could not parse {} at line {}, column {} because {}\n{}",
+                    token, span.line, span.column, self.reason, span
+                )),
+                None => f.write_fmt(format_args!("Could not parse {} because {}", token, self.reason)),
+            }
         }
     }
 
@@ -19,5 +112,37 @@ pub mod err {
 
     }
 
-}
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_token_error_points_at_token() {
+            let input = "$ cd /\n$ cd ..\n$ cd ..\n";
+            let error = TokenError::at(input, &input[15..22], "can't return from root");
+            assert_eq!(
+                error.span,
+                Some(Span { offset: 15, line: 3, column: 1, len: 7, source_line: "$ cd ..".to_string() })
+            );
+            assert_eq!(
+                error.to_string(),
+                "Could not parse $ cd .. at line 3, column 1 because can't return from root\n3 | $ cd ..\n  | ^^^^^^^"
+            );
+            assert_eq!(TokenError::at(input, "cd ..", "not a slice").span.map(|span| (span.line, span.column)), Some((2, 3)));
+            assert!(TokenError::at(input, "ls", "missing").span.is_none());
+        }
 
+        #[test]
+        fn test_long_lines_are_cut_short() {
+            let input = "x".repeat(1000) + "yz" + &"x".repeat(1000);
+            let error = TokenError::at(&input, &input[1000..1002], "unexpected");
+            let message = error.to_string();
+            assert!(message.starts_with("Could not parse yz at line 1, column 1001 because unexpected\n1 | …"));
+            assert!(message.len() < 200);
+            let lines: Vec<&str> = message.lines().collect();
+            let caret = lines[2].find('^').unwrap();
+            assert_eq!(lines[1].chars().skip(caret).take(2).collect::<String>(), "yz");
+            assert_eq!(TokenError::new(input.clone(), "too long").to_string().chars().count(), 41 + "Could not parse  because too long".len());
+        }
+    }
+}
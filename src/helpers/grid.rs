@@ -0,0 +1,842 @@
+//! Two-dimensional grids, as many puzzles are played out on one. `Grid<T>` stores any cell type
+//! row by row, `BitGrid` packs booleans, e.g. to mark visited or visible cells.
+use std::error::Error;
+use std::fmt;
+use std::ops::{BitOrAssign, Index, IndexMut};
+
+use super::err::TokenError;
+use super::parse;
+
+/// A position on a grid, `x` counting columns from the left and `y` rows from the top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Self {
+        Point { x, y }
+    }
+
+    /// The next point in `direction`, `None` past the top or left edge.
+    pub fn step(self, direction: Direction) -> Option<Point> {
+        let (dx, dy) = direction.offset();
+        Some(Point::new(
+            self.x.checked_add_signed(dx)?,
+            self.y.checked_add_signed(dy)?,
+        ))
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point { x, y }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    /// The change in `x` and `y` of one step.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+}
+
+/// Where a ray cast with `Grid::cast` stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cast {
+    /// The number of cells the ray passed, the one that stopped it included.
+    pub distance: usize,
+    /// The cell that stopped the ray, `None` if it left the grid.
+    pub hit: Option<Point>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    X,
+    Y,
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Dimension::X => "X",
+            Dimension::Y => "Y",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexOutOfBoundsError {
+    pub got: usize,
+    pub max: usize,
+    pub dimension: Dimension,
+}
+
+impl fmt::Display for IndexOutOfBoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "Index {} in dimension {} was out of bounds: min: 0, max: {}",
+            self.got, self.dimension, self.max
+        ))
+    }
+}
+
+impl Error for IndexOutOfBoundsError {}
+
+fn check_bounds(
+    width: usize,
+    height: usize,
+    x: usize,
+    y: usize,
+) -> Result<(), IndexOutOfBoundsError> {
+    if x >= width {
+        return Err(IndexOutOfBoundsError {
+            got: x,
+            max: width,
+            dimension: Dimension::X,
+        });
+    }
+    if y >= height {
+        return Err(IndexOutOfBoundsError {
+            got: y,
+            max: height,
+            dimension: Dimension::Y,
+        });
+    }
+    Ok(())
+}
+
+/// A `width` by `height` grid of cells, indexed with `grid[(x, y)]` or `grid[point]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `width` columns from cells listed row by row. `None` unless they fill whole rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Option<Self> {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            return None;
+        }
+        Some(Grid {
+            height: cells.len() / width,
+            cells,
+            width,
+        })
+    }
+
+    /// Parses a character map, one row per non-empty line, mapping each character with `cell`.
+    /// Rows of different lengths and characters `cell` returns `None` for are reported where they are.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, TokenError> {
+        let mut cells = vec![];
+        let mut width = None;
+        for line in parse::lines(input) {
+            let row_start = cells.len();
+            for (i, c) in line.char_indices() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        let token = &line[i..i + c.len_utf8()];
+                        return Err(TokenError::at(input, token, "unexpected character"));
+                    }
+                }
+            }
+            match width {
+                None => width = Some(cells.len()),
+                Some(width) if cells.len() - row_start != width => {
+                    return Err(TokenError::at(
+                        input,
+                        line,
+                        format!("expected a row of {} cells", width),
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+        match width {
+            Some(width) if width > 0 => {
+                Ok(Grid::from_vec(width, cells).expect("every row has the same width"))
+            }
+            _ => Err(TokenError::at(
+                input,
+                input,
+                "expected at least one row of cells",
+            )),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index_of(x, y).ok().map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index_of(x, y).ok().map(|i| &mut self.cells[i])
+    }
+
+    pub fn try_get(&self, x: usize, y: usize) -> Result<&T, IndexOutOfBoundsError> {
+        Ok(&self.cells[self.index_of(x, y)?])
+    }
+
+    /// Replaces a cell, returning what was there.
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> Result<T, IndexOutOfBoundsError> {
+        let i = self.index_of(x, y)?;
+        Ok(std::mem::replace(&mut self.cells[i], value))
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> Result<usize, IndexOutOfBoundsError> {
+        check_bounds(self.width, self.height, x, y)?;
+        Ok(y * self.width + x)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Every row, top to bottom. A grid without columns has rows without cells.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// The cells of column `x`, top to bottom. A grid without rows has columns without cells.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {} of a grid {} wide", x, self.width);
+        self.cells
+            .get(x..)
+            .unwrap_or_default()
+            .iter()
+            .step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (Point::new(i % width, i / width), cell))
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// The up to four cells above, left, right and below `point`.
+    pub fn neighbors(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.offsets(point, &[(0, -1), (-1, 0), (1, 0), (0, 1)])
+    }
+
+    /// The up to eight cells around `point`, diagonals included, row by row.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.offsets(
+            point,
+            &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        )
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        point: Point,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + 'a {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let neighbor = Point::new(
+                point.x.checked_add_signed(*dx)?,
+                point.y.checked_add_signed(*dy)?,
+            );
+            self.contains(neighbor).then_some(neighbor)
+        })
+    }
+
+    /// The cells from `from` in `direction` up to the edge, `from` itself excluded.
+    pub fn ray(&self, from: Point, direction: Direction) -> impl Iterator<Item = Point> + '_ {
+        std::iter::successors(Some(from), move |point| {
+            point.step(direction).filter(|point| self.contains(*point))
+        })
+        .skip(1)
+    }
+
+    /// Walks from `from` in `direction` until a cell for which `stop` is true, or the edge.
+    pub fn cast(
+        &self,
+        from: Point,
+        direction: Direction,
+        mut stop: impl FnMut(&T) -> bool,
+    ) -> Cast {
+        let mut distance = 0;
+        for point in self.ray(from, direction) {
+            distance += 1;
+            if stop(&self[point]) {
+                return Cast {
+                    distance,
+                    hit: Some(point),
+                };
+            }
+        }
+        Cast {
+            distance,
+            hit: None,
+        }
+    }
+
+    /// Every row or column as walked in `direction`, e.g. every row from left to right for `Right`.
+    /// A grid without cells has no lines.
+    pub fn lines(
+        &self,
+        direction: Direction,
+    ) -> impl Iterator<Item = impl Iterator<Item = Point> + '_> + '_ {
+        let (width, height) = (self.width, self.height);
+        let starts: Box<dyn Iterator<Item = Point>> = match direction {
+            _ if width == 0 || height == 0 => Box::new(std::iter::empty()),
+            Direction::Right => Box::new((0..height).map(|y| Point::new(0, y))),
+            Direction::Left => Box::new((0..height).map(move |y| Point::new(width - 1, y))),
+            Direction::Down => Box::new((0..width).map(|x| Point::new(x, 0))),
+            Direction::Up => Box::new((0..width).map(move |x| Point::new(x, height - 1))),
+        };
+        starts.map(move |start| std::iter::once(start).chain(self.ray(start, direction)))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// A read-only window of `width` by `height` cells with its top left corner at `origin`.
+    pub fn view(
+        &self,
+        origin: Point,
+        width: usize,
+        height: usize,
+    ) -> Result<View<'_, T>, IndexOutOfBoundsError> {
+        if width > 0 && height > 0 {
+            check_bounds(
+                self.width,
+                self.height,
+                origin.x + width - 1,
+                origin.y + height - 1,
+            )?;
+        }
+        Ok(View {
+            grid: self,
+            origin,
+            width,
+            height,
+        })
+    }
+}
+
+/// Line-of-sight queries, with cells as heights that hide lower or equal cells behind them.
+impl<T: Ord> Grid<T> {
+    /// The cells seen from outside the grid beyond its `edge`: those higher than every cell
+    /// between them and that edge. Takes one pass over each line.
+    pub fn visible_from(&self, edge: Direction) -> BitGrid {
+        let mut visible = BitGrid::new(self.width, self.height);
+        for line in self.lines(edge.opposite()) {
+            let mut highest = None;
+            for point in line {
+                let cell = &self[point];
+                if highest.is_none_or(|highest| cell > highest) {
+                    highest = Some(cell);
+                    visible
+                        .set(point.x, point.y, true)
+                        .expect("the grids have the same size");
+                }
+            }
+        }
+        visible
+    }
+
+    /// How far every cell sees in `direction`: the `distance` of
+    /// `cast(point, direction, |cell| cell >= &self[point])`. A monotonic stack
+    /// answers each line in one pass instead of casting a ray from every cell.
+    pub fn view_distances(&self, direction: Direction) -> Grid<usize> {
+        let mut distances = Grid {
+            cells: vec![0; self.cells.len()],
+            width: self.width,
+            height: self.height,
+        };
+        // Walking against `direction`, the cells ahead of a cell are the ones walked before it.
+        // The stack keeps those not hidden from it by a cell ahead, the nearest on top.
+        let mut stack: Vec<(usize, &T)> = vec![];
+        for line in self.lines(direction.opposite()) {
+            stack.clear();
+            for (i, point) in line.enumerate() {
+                let cell = &self[point];
+                while stack.last().is_some_and(|(_, ahead)| *ahead < cell) {
+                    stack.pop();
+                }
+                distances[point] = stack.last().map_or(i, |(blocker, _)| i - blocker);
+                stack.push((i, cell));
+            }
+        }
+        distances
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Mirrors the grid along its diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        self.rebuild(self.height, self.width, |x, y| Point::new(y, x))
+    }
+
+    /// Turns the grid a quarter clockwise.
+    pub fn rotate_right(&self) -> Self {
+        self.rebuild(self.height, self.width, |x, y| {
+            Point::new(y, self.height - 1 - x)
+        })
+    }
+
+    /// Turns the grid a quarter counterclockwise.
+    pub fn rotate_left(&self) -> Self {
+        self.rebuild(self.height, self.width, |x, y| {
+            Point::new(self.width - 1 - y, x)
+        })
+    }
+
+    /// A `width` by `height` grid whose cell `(x, y)` is this grid's cell at `source(x, y)`.
+    fn rebuild(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> Point) -> Self {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self[source(x, y)].clone());
+            }
+        }
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.index_of(x, y) {
+            Ok(i) => &self.cells[i],
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        match self.index_of(x, y) {
+            Ok(i) => &mut self.cells[i],
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        &self[(point.x, point.y)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        &mut self[(point.x, point.y)]
+    }
+}
+
+/// One line per row, each cell formatted with the same options as the grid itself,
+/// so `format!("{:3}", grid)` pads every cell to three characters.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                cell.fmt(f)?;
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+/// A rectangular part of a grid, see `Grid::view`. Positions are relative to the view.
+#[derive(Debug, Clone, Copy)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Point,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        check_bounds(self.width, self.height, x, y).ok()?;
+        self.grid.get(self.origin.x + x, self.origin.y + y)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.height)
+            .map(|y| &self.grid.row(self.origin.y + y)[self.origin.x..self.origin.x + self.width])
+    }
+}
+
+impl<T: Clone> View<'_, T> {
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid {
+            cells: self.rows().flatten().cloned().collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+/// A grid of booleans packed into bits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    bits: Vec<u64>,
+    width: usize,
+    height: usize,
+}
+
+impl BitGrid {
+    /// A grid with every cell `false`.
+    pub fn new(width: usize, height: usize) -> Self {
+        BitGrid {
+            bits: vec![0; (width * height).div_ceil(64)],
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> Result<(usize, u64), IndexOutOfBoundsError> {
+        check_bounds(self.width, self.height, x, y)?;
+        let i = y * self.width + x;
+        Ok((i / 64, 1 << (i % 64)))
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<bool> {
+        let (word, mask) = self.index_of(x, y).ok()?;
+        Some(self.bits[word] & mask != 0)
+    }
+
+    /// Sets a cell, returning what it was.
+    pub fn set(&mut self, x: usize, y: usize, value: bool) -> Result<bool, IndexOutOfBoundsError> {
+        let (word, mask) = self.index_of(x, y)?;
+        let was = self.bits[word] & mask != 0;
+        match value {
+            true => self.bits[word] |= mask,
+            false => self.bits[word] &= !mask,
+        }
+        Ok(was)
+    }
+
+    /// The number of `true` cells.
+    pub fn count(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// The positions of the `true` cells, row by row.
+    pub fn iter_set(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.height)
+            .flat_map(move |y| (0..self.width).map(move |x| Point::new(x, y)))
+            .filter(|point| self.get(point.x, point.y) == Some(true))
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut bits = BitGrid::new(grid.width(), grid.height());
+        for (point, _) in grid.iter().filter(|(_, set)| **set) {
+            bits.set(point.x, point.y, true)
+                .expect("the grids have the same size");
+        }
+        bits
+    }
+}
+
+impl BitOrAssign<&BitGrid> for BitGrid {
+    fn bitor_assign(&mut self, other: &BitGrid) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "grids of different sizes"
+        );
+        for (word, other) in self.bits.iter_mut().zip(&other.bits) {
+            *word |= other;
+        }
+    }
+}
+
+/// `#` for `true` and `.` for `false`, one line per row, as puzzles draw them. `helpers::ocr` reads this.
+impl fmt::Display for BitGrid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                f.write_str(if self.get(x, y) == Some(true) {
+                    "#"
+                } else {
+                    "."
+                })?;
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u8> {
+        Grid::parse(input, |c| c.to_digit(10).map(|d| d as u8)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_access() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid[Point::new(0, 1)], 4);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(
+            grid.try_get(0, 2),
+            Err(IndexOutOfBoundsError {
+                got: 2,
+                max: 2,
+                dimension: Dimension::Y
+            })
+        );
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.to_string(), "123\n456\n");
+        assert_eq!(grid.map(|d| u32::from(*d) * 3).to_string(), "369\n121518\n");
+        assert_eq!(
+            format!("{:3}", grid.map(|d| u32::from(*d) * 3)),
+            "  3  6  9\n 12 15 18\n"
+        );
+
+        let error = Grid::parse("12\n3x\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((error.token.as_str(), error.span.unwrap().column), ("x", 2));
+        let error = Grid::parse("12\n345\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(error.token, "345");
+        assert!(Grid::parse("\n", |c| c.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = digits("123\n456\n789\n");
+        let values = |points: Vec<Point>| points.into_iter().map(|p| grid[p]).collect::<Vec<_>>();
+        assert_eq!(
+            values(grid.neighbors(Point::new(1, 1)).collect()),
+            vec![2, 4, 6, 8]
+        );
+        assert_eq!(
+            values(grid.neighbors(Point::new(0, 0)).collect()),
+            vec![2, 4]
+        );
+        assert_eq!(
+            values(grid.neighbors8(Point::new(2, 2)).collect()),
+            vec![5, 6, 8]
+        );
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_transform_and_view() {
+        let grid = digits("123\n456\n");
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36\n");
+        assert_eq!(grid.rotate_right().to_string(), "41\n52\n63\n");
+        assert_eq!(grid.rotate_left().to_string(), "36\n25\n14\n");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+
+        let view = grid.view(Point::new(1, 0), 2, 2).unwrap();
+        assert_eq!(view.get(1, 1), Some(&6));
+        assert_eq!(view.get(2, 0), None);
+        assert_eq!(view.to_grid().to_string(), "23\n56\n");
+        assert!(grid.view(Point::new(2, 0), 2, 1).is_err());
+    }
+
+    #[test]
+    fn test_line_of_sight() {
+        let grid = digits("30373\n25512\n65332\n33549\n35390\n");
+        let middle = Point::new(2, 3);
+        assert_eq!(
+            grid.ray(middle, Direction::Up).collect::<Vec<_>>(),
+            vec![Point::new(2, 2), Point::new(2, 1), Point::new(2, 0)]
+        );
+        let cast = grid.cast(middle, Direction::Up, |cell| *cell >= 5);
+        assert_eq!(
+            cast,
+            Cast {
+                distance: 2,
+                hit: Some(Point::new(2, 1))
+            }
+        );
+        let cast = grid.cast(middle, Direction::Right, |cell| *cell > 9);
+        assert_eq!(
+            cast,
+            Cast {
+                distance: 2,
+                hit: None
+            }
+        );
+        assert_eq!(
+            grid.lines(Direction::Up)
+                .next()
+                .unwrap()
+                .map(|p| grid[p])
+                .collect::<Vec<_>>(),
+            vec![3, 3, 6, 2, 3]
+        );
+
+        let left = grid.visible_from(Direction::Left);
+        assert_eq!(left.to_string(), "#..#.\n##...\n#....\n#.#.#\n##.#.\n");
+        let mut visible = BitGrid::new(5, 5);
+        for edge in Direction::ALL {
+            visible |= &grid.visible_from(edge);
+        }
+        assert_eq!(visible.count(), 21);
+
+        for direction in Direction::ALL {
+            let distances = grid.view_distances(direction);
+            for (point, cell) in grid.iter() {
+                let cast = grid.cast(point, direction, |other| other >= cell);
+                assert_eq!(
+                    distances[point], cast.distance,
+                    "{:?} from {:?}",
+                    direction, point
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_grids_without_cells() {
+        for (width, height) in [(0, 3), (3, 0), (0, 0)] {
+            let grid = Grid::new(width, height, 1u8);
+            assert_eq!(grid.rows().count(), height);
+            assert!(grid.rows().all(|row| row.is_empty()));
+            assert_eq!(grid.columns().count(), width);
+            assert!(grid.columns().all(|mut column| column.next().is_none()));
+            for direction in Direction::ALL {
+                assert_eq!(grid.lines(direction).count(), 0);
+                assert_eq!(grid.visible_from(direction).count(), 0);
+                assert!(grid.view_distances(direction).cells().is_empty());
+            }
+            assert_eq!(grid.iter().count(), 0);
+            assert_eq!(grid.get(0, 0), None);
+            assert_eq!(grid.transpose().width(), height);
+            assert_eq!(grid.rotate_left().height(), width);
+            assert_eq!(grid.to_string(), "\n".repeat(height));
+
+            let bits = BitGrid::new(width, height);
+            assert_eq!(bits.count(), 0);
+            assert_eq!(bits.get(0, 0), None);
+            assert!(bits.iter_set().next().is_none());
+            assert_eq!(bits.to_string(), "\n".repeat(height));
+        }
+    }
+
+    #[test]
+    fn test_bit_grid() {
+        let mut bits = BitGrid::new(70, 2);
+        assert_eq!(bits.set(69, 1, true), Ok(false));
+        assert_eq!(bits.set(69, 1, true), Ok(true));
+        bits.set(1, 0, true).unwrap();
+        assert!(bits.set(70, 0, true).is_err());
+        assert_eq!(bits.count(), 2);
+        assert_eq!(
+            bits.iter_set().collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(69, 1)]
+        );
+
+        let grid = Grid::parse("#.\n.#\n", |c| Some(c == '#')).unwrap();
+        assert_eq!(BitGrid::from(&grid).to_string(), "#.\n.#\n");
+    }
+}
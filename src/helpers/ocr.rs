@@ -0,0 +1,220 @@
+//! Reads the block letters some puzzles draw as their answer, e.g. on a simulated screen.
+//! Lit pixels are `#`, `1` or `█`, anything else is dark. Letters are separated by dark columns
+//! and are either 6 rows high (the 4x6 font) or 10 rows high (the 6x10 font).
+use std::error::Error;
+use std::fmt;
+
+/// The 4x6 font. A few letters are narrower or wider than 4 columns, so glyphs are stored without
+/// their empty columns.
+const SMALL: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The 6x10 font.
+#[rustfmt::skip]
+const LARGE: &[(char, [&str; 10])] = &[
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// Nothing is lit.
+    Empty,
+    /// The lit rows span neither 6 nor 10 rows.
+    Height(usize),
+    /// What could be read, with `?` for each glyph that is not in the font, and the columns of those glyphs.
+    Unknown {
+        read: String,
+        columns: Vec<(usize, usize)>,
+    },
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OcrError::Empty => f.write_str("there are no letters, nothing is lit"),
+            OcrError::Height(height) => f.write_fmt(format_args!(
+                "letters are 6 or 10 rows high, these span {} rows",
+                height
+            )),
+            OcrError::Unknown { read, columns } => f.write_fmt(format_args!(
+                "could not read \"{}\", unknown glyphs at columns {}",
+                read,
+                columns
+                    .iter()
+                    .map(|(start, end)| format!("{}-{}", start, end))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+/// Reads letters from their textual rendering, like `Answer::Art` or the `Display` of a boolean grid.
+pub fn read(text: &str) -> Result<String, OcrError> {
+    let rows: Vec<Vec<bool>> = text
+        .lines()
+        .map(|line| line.chars().map(|c| matches!(c, '#' | '1' | '█')).collect())
+        .collect();
+    read_rows(&rows)
+}
+
+/// Reads letters from rows of pixels, `true` being lit. Rows may differ in length.
+pub fn read_rows<R: AsRef<[bool]>>(rows: &[R]) -> Result<String, OcrError> {
+    let width = rows.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+    read_grid(width, rows.len(), |x, y| {
+        rows[y].as_ref().get(x).copied().unwrap_or(false)
+    })
+}
+
+/// Reads letters from any grid of `width` by `height` pixels, asking `lit(x, y)` for each pixel.
+pub fn read_grid(
+    width: usize,
+    height: usize,
+    lit: impl Fn(usize, usize) -> bool,
+) -> Result<String, OcrError> {
+    let lit_rows: Vec<usize> = (0..height)
+        .filter(|&y| (0..width).any(|x| lit(x, y)))
+        .collect();
+    let (top, bottom) = match (lit_rows.first(), lit_rows.last()) {
+        (Some(top), Some(bottom)) => (*top, bottom + 1),
+        _ => return Err(OcrError::Empty),
+    };
+    let column_lit = |x: usize| (top..bottom).any(|y| lit(x, y));
+
+    let mut read = String::new();
+    let mut columns = vec![];
+    let mut x = 0;
+    while x < width {
+        if !column_lit(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < width && column_lit(x) {
+            x += 1;
+        }
+        let glyph: Vec<String> = (top..bottom)
+            .map(|y| {
+                (start..x)
+                    .map(|x| if lit(x, y) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        let letter = match bottom - top {
+            6 => find(SMALL, &glyph),
+            10 => find(LARGE, &glyph),
+            height => return Err(OcrError::Height(height)),
+        };
+        match letter {
+            Some(letter) => read.push(letter),
+            None => {
+                read.push('?');
+                columns.push((start, x - 1));
+            }
+        }
+    }
+
+    match columns.is_empty() {
+        true => Ok(read),
+        false => Err(OcrError::Unknown { read, columns }),
+    }
+}
+
+fn find<const N: usize>(font: &[(char, [&str; N])], glyph: &[String]) -> Option<char> {
+    font.iter()
+        .find(|(_, rows)| rows.iter().eq(glyph.iter()))
+        .map(|(letter, _)| *letter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Draws `letters` like a puzzle would, one dark column between glyphs and `.` for dark pixels.
+    fn draw<const N: usize>(font: &[(char, [&str; N])], letters: &str) -> String {
+        let glyphs: Vec<&[&str; N]> = letters
+            .chars()
+            .map(|c| &font.iter().find(|(letter, _)| *letter == c).unwrap().1)
+            .collect();
+        (0..N)
+            .map(|y| {
+                glyphs
+                    .iter()
+                    .map(|glyph| glyph[y])
+                    .collect::<Vec<_>>()
+                    .join(".")
+                    + ".\n"
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_read_small_and_large() {
+        assert_eq!(read(&draw(SMALL, "EHPZPJGL")).unwrap(), "EHPZPJGL");
+        assert_eq!(read(&draw(SMALL, "YIKES")).unwrap(), "YIKES");
+        assert_eq!(read(&draw(LARGE, "HJZNXPGK")).unwrap(), "HJZNXPGK");
+
+        let grid = draw(SMALL, "BUG").replace('#', "1").replace('.', "0");
+        assert_eq!(read(&format!("0000000000000000\n{}", grid)).unwrap(), "BUG");
+        let rows: Vec<Vec<bool>> = draw(SMALL, "AC")
+            .lines()
+            .map(|line| line.chars().map(|c| c == '#').collect())
+            .collect();
+        assert_eq!(read_rows(&rows).unwrap(), "AC");
+    }
+
+    #[test]
+    fn test_read_errors() {
+        assert_eq!(read("....\n...."), Err(OcrError::Empty));
+        assert_eq!(read("#\n#\n#"), Err(OcrError::Height(3)));
+
+        let mut text = draw(SMALL, "FOO");
+        text = text.replacen(".##.", "####", 1);
+        let error = read(&text).unwrap_err();
+        assert_eq!(
+            error,
+            OcrError::Unknown {
+                read: "F?O".to_string(),
+                columns: vec![(5, 8)]
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "could not read \"F?O\", unknown glyphs at columns 5-8"
+        );
+    }
+}
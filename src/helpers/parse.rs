@@ -0,0 +1,145 @@
+//! Small parsers for puzzle lines that return typed values, with errors pointing into the input.
+//! `input` is always the whole text being parsed and `line` a slice of it, so every `TokenError`
+//! knows its line and column. When there is no larger input, pass the line as both.
+use std::any::type_name;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::err::TokenError;
+
+/// The non-empty lines of `input`.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().filter(|line| !line.is_empty())
+}
+
+/// Parses `token`, a slice of `input`, as a `T`.
+pub fn field<T: FromStr>(input: &str, token: &str) -> Result<T, TokenError>
+where
+    T::Err: Display,
+{
+    token.parse().map_err(|e| {
+        let name = type_name::<T>().rsplit("::").next().unwrap_or_default();
+        TokenError::at(input, token, format!("expected a {}: {}", name, e))
+    })
+}
+
+/// Splits `line`, a slice of `input`, at every `separator` and expects exactly `N` parts.
+pub fn split_n<'a, const N: usize>(
+    input: &str,
+    line: &'a str,
+    separator: &str,
+) -> Result<[&'a str; N], TokenError> {
+    let parts: Vec<&str> = line.split(separator).collect();
+    parts.try_into().map_err(|parts: Vec<&str>| {
+        TokenError::at(
+            input,
+            line,
+            format!(
+                "expected {} parts separated by {:?}, found {}",
+                N,
+                separator,
+                parts.len()
+            ),
+        )
+    })
+}
+
+/// Matches `line`, a slice of `input`, against `pattern` and returns the text of each `{}` in it.
+/// A field ends where the text following it in the pattern first appears, so fields need text between them.
+pub fn fields<'a>(input: &str, line: &'a str, pattern: &str) -> Result<Vec<&'a str>, TokenError> {
+    let mut literals = pattern.split("{}");
+    let prefix = literals.next().unwrap_or_default();
+    let mut rest = line
+        .strip_prefix(prefix)
+        .ok_or_else(|| TokenError::at(input, line, format!("expected {:?}", prefix)))?;
+
+    let mut fields = vec![];
+    for literal in literals {
+        let end = match literal {
+            "" => rest.len(),
+            literal => rest
+                .find(literal)
+                .ok_or_else(|| TokenError::at(input, rest, format!("expected {:?}", literal)))?,
+        };
+        fields.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+    match rest {
+        "" => Ok(fields),
+        rest => Err(TokenError::at(input, rest, "unexpected text at the end")),
+    }
+}
+
+/// `parse!(input, line, "move {} from {} to {}" => u32, usize, usize)` matches `line`, a slice of `input`,
+/// against the pattern and parses each field as the type at its position, giving a
+/// `Result<(u32, usize, usize), TokenError>`. `parse!(line, ...)` is short for `parse!(line, line, ...)`.
+#[macro_export]
+macro_rules! parse {
+    ($line:expr, $pattern:expr => $($ty:ty),+ $(,)?) => {{
+        let line: &str = $line;
+        $crate::parse!(line, line, $pattern => $($ty),+)
+    }};
+    ($input:expr, $line:expr, $pattern:expr => $($ty:ty),+ $(,)?) => {{
+        let input: &str = $input;
+        $crate::helpers::parse::fields(input, $line, $pattern).and_then(|fields| {
+            let mut fields = fields.into_iter();
+            let parsed = ($(
+                $crate::helpers::parse::field::<$ty>(
+                    input,
+                    fields.next().expect("the pattern has fewer fields than types"),
+                )?,
+            )+);
+            assert!(fields.next().is_none(), "the pattern has more fields than types");
+            Ok(parsed)
+        })
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pattern() {
+        let input = "move 1 from 2 to 3\nmove 10 from 9 to 8\n";
+        let parsed: Vec<(u32, usize, usize)> = lines(input)
+            .map(|line| crate::parse!(input, line, "move {} from {} to {}" => u32, usize, usize))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(parsed, vec![(1, 2, 3), (10, 9, 8)]);
+        assert_eq!(
+            crate::parse!("2-4,6-8", "{}-{},{}-{}" => u32, u32, u32, u32).unwrap(),
+            (2, 4, 6, 8)
+        );
+        assert_eq!(
+            crate::parse!("A Y", "{} {}" => char, char).unwrap(),
+            ('A', 'Y')
+        );
+        assert_eq!(split_n::<2>("A Y", "A Y", " ").unwrap(), ["A", "Y"]);
+    }
+
+    #[test]
+    fn test_errors_point_at_the_field() {
+        let input = "move 1 from 2 to 3\nmove x from 9 to 8\n";
+        let line = input.lines().nth(1).unwrap();
+        let error =
+            crate::parse!(input, line, "move {} from {} to {}" => u32, usize, usize).unwrap_err();
+        assert_eq!(error.token, "x");
+        assert_eq!(
+            error.span.map(|span| (span.line, span.column)),
+            Some((2, 6))
+        );
+        assert!(error.reason.starts_with("expected a u32: "));
+
+        let error = fields(input, line, "move {} onto {}").unwrap_err();
+        assert_eq!(error.reason, "expected \" onto \"");
+        assert_eq!(error.token, "x from 9 to 8");
+        let error = fields("1-2", "1-2", "{}-{}!").unwrap_err();
+        assert_eq!(error.reason, "expected \"!\"");
+        assert_eq!(
+            fields("1-2, 3", "1-2, 3", "{}-{},").unwrap_err().token,
+            " 3"
+        );
+        assert!(split_n::<2>("A Y Z", "A Y Z", " ").is_err());
+    }
+}
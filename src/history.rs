@@ -0,0 +1,339 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::error;
+
+use serde::{Deserialize, Serialize};
+
+use crate::report::{Record, Status};
+use crate::{ANSI_BOLD, ANSI_RESET};
+
+pub const DEFAULT_HISTORY_FILE: &str = "target/aoc-bench.jsonl";
+
+/// One line of the history file: the timing of a single part in a single `cargo all` run.
+/// All samples of a run share the same `timestamp` and `commit`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Sample {
+    /// `0` in history files written before years were recorded.
+    #[serde(default)]
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub commit: Option<String>,
+    /// Milliseconds since the unix epoch at the start of the run.
+    pub timestamp: u64,
+    pub profile: String,
+    pub baseline: Option<String>,
+    pub median_ns: u64,
+    pub samples: usize,
+    /// Whether `median_ns` is the median of `--bench` samples rather than a single timing.
+    /// `false` in history files written before this was recorded.
+    #[serde(default)]
+    pub benched: bool,
+}
+
+/// All samples recorded by one run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub commit: Option<String>,
+    pub timestamp: u64,
+    pub profile: String,
+    pub baseline: Option<String>,
+    /// Whether the run was made with `--bench`.
+    pub benched: bool,
+    pub samples: Vec<Sample>,
+}
+
+impl Run {
+    /// Collects the timings of every solved part. Benchmarked parts contribute their median, others their single timing.
+    pub fn from_records(records: &[Record], baseline: Option<String>) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+        let commit = git_commit();
+        let profile = profile().to_string();
+        let benched = records.iter().any(|record| record.median_ns.is_some());
+
+        let samples = records
+            .iter()
+            .filter(|record| record.status == Status::Solved)
+            .filter_map(|record| {
+                let median_ns = record.median_ns.or(record.elapsed_ns)?;
+                Some(Sample {
+                    year: record.year,
+                    day: record.day,
+                    part: record.part,
+                    commit: commit.clone(),
+                    timestamp,
+                    profile: profile.clone(),
+                    baseline: baseline.clone(),
+                    median_ns,
+                    samples: record.samples.unwrap_or(1),
+                    benched,
+                })
+            })
+            .collect();
+
+        Run {
+            commit,
+            timestamp,
+            profile,
+            baseline,
+            benched,
+            samples,
+        }
+    }
+
+    fn median(&self, year: u16, day: u8, part: u8) -> Option<u64> {
+        self.samples
+            .iter()
+            .find(|s| s.year == year && s.day == day && s.part == part)
+            .map(|s| s.median_ns)
+    }
+}
+
+pub fn default_path() -> PathBuf {
+    crate::input::project_dir().join(DEFAULT_HISTORY_FILE)
+}
+
+fn profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+/// Short hash of `HEAD`, suffixed with `-dirty` if the work tree has changes. `None` outside of a git checkout.
+fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(crate::input::project_dir())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let hash = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    let dirty = Command::new("git")
+        .args(["status", "--porcelain"])
+        .current_dir(crate::input::project_dir())
+        .output()
+        .map(|o| !o.stdout.is_empty())
+        .unwrap_or(false);
+    Some(if dirty { format!("{}-dirty", hash) } else { hash })
+}
+
+pub fn append(path: &Path, run: &Run) -> Result<(), Box<dyn error::Error>> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for sample in &run.samples {
+        serde_json::to_writer(&mut file, sample)?;
+        writeln!(file)?;
+    }
+    Ok(())
+}
+
+/// Reads every run in the history file, oldest first. A missing file is an empty history.
+pub fn load(path: &Path) -> Result<Vec<Run>, Box<dyn error::Error>> {
+    let file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut runs: Vec<Run> = vec![];
+    let mut index: HashMap<(u64, Option<String>), usize> = HashMap::new();
+    for line in io::BufReader::new(file).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let sample: Sample = serde_json::from_str(&line)?;
+        let key = (sample.timestamp, sample.commit.clone());
+        let i = *index.entry(key).or_insert_with(|| {
+            runs.push(Run {
+                commit: sample.commit.clone(),
+                timestamp: sample.timestamp,
+                profile: sample.profile.clone(),
+                baseline: sample.baseline.clone(),
+                benched: sample.benched,
+                samples: vec![],
+            });
+            runs.len() - 1
+        });
+        runs[i].samples.push(sample);
+    }
+    Ok(runs)
+}
+
+/// Picks the run to compare `current` against: the latest run labelled `name`, or else the latest earlier run.
+/// Only runs built with the same profile are considered, and only benchmarked runs for a benchmarked one,
+/// as a single timing is too noisy to compare against a median.
+pub fn find_baseline<'a>(runs: &'a [Run], current: &Run, name: Option<&str>) -> Option<&'a Run> {
+    runs.iter()
+        .rev()
+        .filter(|run| run.profile == current.profile && run.benched == current.benched)
+        .filter(|run| !(run.timestamp == current.timestamp && run.commit == current.commit))
+        .find(|run| match name {
+            Some(name) => run.baseline.as_deref() == Some(name),
+            None => true,
+        })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+    pub regressed: bool,
+}
+
+impl Change {
+    pub fn percent(&self) -> f64 {
+        (self.current_ns as f64 / self.baseline_ns.max(1) as f64 - 1_f64) * 100_f64
+    }
+}
+
+/// Compares every part timed in both runs. A part regressed if its median grew by more than `threshold` percent.
+pub fn compare(baseline: &Run, current: &Run, threshold: f64) -> Vec<Change> {
+    current
+        .samples
+        .iter()
+        .filter_map(|sample| {
+            let baseline_ns = baseline.median(sample.year, sample.day, sample.part)?;
+            let change = Change {
+                year: sample.year,
+                day: sample.day,
+                part: sample.part,
+                baseline_ns,
+                current_ns: sample.median_ns,
+                regressed: false,
+            };
+            Some(Change {
+                regressed: change.percent() > threshold,
+                ..change
+            })
+        })
+        .collect()
+}
+
+pub fn print_changes(baseline: &Run, changes: &[Change], out: &mut impl Write) -> io::Result<()> {
+    writeln!(
+        out,
+        "{}Compared against {} ({}){}",
+        ANSI_BOLD,
+        baseline.commit.as_deref().unwrap_or("unknown commit"),
+        baseline
+            .baseline
+            .as_deref()
+            .map(|name| format!("baseline \"{name}\""))
+            .unwrap_or_else(|| format!("run at {}", baseline.timestamp)),
+        ANSI_RESET
+    )?;
+    for change in changes {
+        writeln!(
+            out,
+            "{} Day {:02} Part {}: {:>12} ns -> {:>12} ns ({:+.1}%){}",
+            change.year,
+            change.day,
+            change.part,
+            change.baseline_ns,
+            change.current_ns,
+            change.percent(),
+            if change.regressed { " REGRESSED" } else { "" }
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(timestamp: u64, baseline: Option<&str>, medians: &[(u8, u8, u64)]) -> Run {
+        run_of_kind(timestamp, baseline, true, medians)
+    }
+
+    fn run_of_kind(
+        timestamp: u64,
+        baseline: Option<&str>,
+        benched: bool,
+        medians: &[(u8, u8, u64)],
+    ) -> Run {
+        let samples = medians
+            .iter()
+            .map(|(day, part, median_ns)| Sample {
+                year: 2022,
+                day: *day,
+                part: *part,
+                commit: Some("abc1234".to_string()),
+                timestamp,
+                profile: "release".to_string(),
+                baseline: baseline.map(String::from),
+                median_ns: *median_ns,
+                samples: 1,
+                benched,
+            })
+            .collect();
+        Run {
+            commit: Some("abc1234".to_string()),
+            timestamp,
+            profile: "release".to_string(),
+            baseline: baseline.map(String::from),
+            benched,
+            samples,
+        }
+    }
+
+    #[test]
+    fn test_round_trip_and_baseline_selection() {
+        let path = std::env::temp_dir().join(format!("aoc-bench-test-{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let named = run(1, Some("main"), &[(1, 1, 100)]);
+        let previous = run(2, None, &[(1, 1, 200)]);
+        let unbenched = run_of_kind(3, None, false, &[(1, 1, 900)]);
+        let current = run(4, None, &[(1, 1, 150)]);
+        for r in [&named, &previous, &unbenched, &current] {
+            append(&path, r).unwrap();
+        }
+
+        let runs = load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            runs,
+            vec![named.clone(), previous.clone(), unbenched.clone(), current.clone()]
+        );
+
+        assert_eq!(find_baseline(&runs, &current, None), Some(&previous));
+        let next = run_of_kind(5, None, false, &[(1, 1, 800)]);
+        assert_eq!(find_baseline(&runs, &next, None), Some(&unbenched));
+        assert_eq!(find_baseline(&runs, &current, Some("main")), Some(&named));
+        assert_eq!(find_baseline(&runs, &current, Some("other")), None);
+    }
+
+    #[test]
+    fn test_compare_flags_regressions_over_threshold() {
+        let baseline = run(1, None, &[(1, 1, 100), (1, 2, 100), (2, 1, 100)]);
+        let current = run(2, None, &[(1, 1, 105), (1, 2, 150), (3, 1, 100)]);
+
+        let changes = compare(&baseline, &current, 10_f64);
+        assert_eq!(changes.len(), 2);
+        assert!(!changes[0].regressed);
+        assert!(changes[1].regressed);
+        assert!((changes[1].percent() - 50_f64).abs() < 1e-9);
+    }
+}
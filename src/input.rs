@@ -0,0 +1,291 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+use std::{env, error, fmt, fs, io, process};
+
+/// Overrides the directory personal inputs are read from (instead of `src/inputs`). Inputs are still namespaced by year.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Selects the year commands work on when no year is passed.
+pub const YEAR_VAR: &str = "AOC_YEAR";
+
+/// The repository root, fixed at compile time so binaries and tests work from any working directory.
+pub fn project_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// The year used when none is given: `AOC_YEAR` if set, otherwise the latest year in `src/solutions`.
+pub fn default_year() -> Option<u16> {
+    if let Some(year) = env::var(YEAR_VAR).ok().and_then(|year| year.parse().ok()) {
+        return Some(year);
+    }
+    fs::read_dir(project_dir().join("src").join("solutions"))
+        .ok()?
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse::<u16>().ok())
+        .max()
+}
+
+/// Why a day's input (or example) file could not be loaded.
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Empty(PathBuf),
+    Unreadable(PathBuf, io::Error),
+    NotUtf8(PathBuf),
+}
+
+impl InputError {
+    fn from_io(path: &Path, e: io::Error) -> Self {
+        match e.kind() {
+            io::ErrorKind::NotFound => InputError::Missing(path.to_path_buf()),
+            io::ErrorKind::InvalidData => InputError::NotUtf8(path.to_path_buf()),
+            _ => InputError::Unreadable(path.to_path_buf(), e),
+        }
+    }
+
+    /// Missing and empty files mean the input simply was not downloaded yet.
+    pub fn is_absent(&self) -> bool {
+        matches!(self, InputError::Missing(_) | InputError::Empty(_))
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Missing(path) => f.write_fmt(format_args!("\"{}\" does not exist", path.display())),
+            InputError::Empty(path) => f.write_fmt(format_args!("\"{}\" is empty", path.display())),
+            InputError::Unreadable(path, e) => {
+                f.write_fmt(format_args!("could not read \"{}\": {}", path.display(), e))
+            }
+            InputError::NotUtf8(path) => {
+                f.write_fmt(format_args!("\"{}\" is not valid UTF-8", path.display()))
+            }
+        }
+    }
+}
+
+impl error::Error for InputError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            InputError::Unreadable(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Where a day's input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => f.write_str("stdin"),
+            Source::File(path) => f.write_fmt(format_args!("\"{}\"", path.display())),
+        }
+    }
+}
+
+/// Command line overrides for input resolution: `--input <path>` (`-` for stdin) and `--verbose`.
+#[derive(Debug, Clone, Default)]
+pub struct InputOptions {
+    pub input: Option<String>,
+    pub verbose: bool,
+}
+
+impl InputOptions {
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(InputOptions {
+            input: args.opt_value_from_str("--input")?,
+            verbose: args.contains(["-v", "--verbose"]),
+        })
+    }
+}
+
+/// Resolves `src/<folder>/<year>/NN.txt`. For personal inputs (`folder == "inputs"`), `--input` wins over
+/// `AOC_INPUT_DIR`, which wins over the copy in the repository. Examples always come from the repository.
+pub fn resolve(folder: &str, year: u16, day: u8, options: &InputOptions) -> Source {
+    let file_name = format!("{:02}.txt", day);
+    let source = match (folder, &options.input, env::var_os(INPUT_DIR_VAR)) {
+        ("inputs", Some(input), _) if input == "-" => Source::Stdin,
+        ("inputs", Some(input), _) => Source::File(PathBuf::from(input)),
+        ("inputs", None, Some(dir)) => {
+            Source::File(PathBuf::from(dir).join(year.to_string()).join(file_name))
+        }
+        _ => Source::File(
+            project_dir()
+                .join("src")
+                .join(folder)
+                .join(year.to_string())
+                .join(file_name),
+        ),
+    };
+    if options.verbose {
+        eprintln!("Reading {} day {:02} {} from {}", year, day, folder, source);
+    }
+    source
+}
+
+/// Where `resolve` looks for `src/<folder>/<year>/NN.txt` without `--input`, e.g. to download an input to.
+pub fn input_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    match resolve(folder, year, day, &InputOptions::default()) {
+        Source::File(path) => path,
+        Source::Stdin => unreachable!("stdin is only used with --input -"),
+    }
+}
+
+/// Reads `src/<folder>/<year>/NN.txt`, or the input in `AOC_INPUT_DIR`. Panics if that fails.
+pub fn read_file(folder: &str, year: u16, day: u8) -> String {
+    try_read_file(folder, year, day).unwrap_or_else(|e| panic!("could not open input file: {}", e))
+}
+
+/// Like `read_file`, for solutions and tests. Command line overrides such as `--input` are left to the
+/// runner, which parses its own arguments and calls `load`.
+pub fn try_read_file(folder: &str, year: u16, day: u8) -> Result<String, InputError> {
+    load(folder, year, day, &InputOptions::default())
+}
+
+pub fn load(folder: &str, year: u16, day: u8, options: &InputOptions) -> Result<String, InputError> {
+    match resolve(folder, year, day, options) {
+        Source::Stdin => read_stdin(),
+        Source::File(path) => read_path(&path),
+    }
+}
+
+/// Stdin can only be consumed once, but solutions like day 5 open their input once per part.
+fn read_stdin() -> Result<String, InputError> {
+    static STDIN: OnceLock<Result<String, io::ErrorKind>> = OnceLock::new();
+    let stdin = STDIN.get_or_init(|| {
+        let mut contents = String::new();
+        io::stdin()
+            .read_to_string(&mut contents)
+            .map(|_| contents)
+            .map_err(|e| e.kind())
+    });
+    let path = Path::new("-");
+    match stdin {
+        Ok(contents) if contents.is_empty() => Err(InputError::Empty(path.to_path_buf())),
+        Ok(contents) => Ok(contents.clone()),
+        Err(kind) => Err(InputError::from_io(path, io::Error::from(*kind))),
+    }
+}
+
+pub fn read_path(path: &Path) -> Result<String, InputError> {
+    let contents = fs::read_to_string(path).map_err(|e| InputError::from_io(path, e))?;
+    if contents.is_empty() {
+        return Err(InputError::Empty(path.to_path_buf()));
+    }
+    Ok(contents)
+}
+
+pub fn open_file_buffer(
+    folder: &str,
+    year: u16,
+    day: u8,
+) -> Result<io::BufReader<Box<dyn Read>>, InputError> {
+    let filepath = input_path(folder, year, day);
+
+    let f = fs::File::open(&filepath).map_err(|e| InputError::from_io(&filepath, e))?;
+    let metadata = f.metadata().map_err(|e| InputError::from_io(&filepath, e))?;
+    if metadata.len() == 0 {
+        return Err(InputError::Empty(filepath));
+    }
+
+    Ok(io::BufReader::new(Box::new(f)))
+}
+
+/// Writes `contents` to a temporary file next to `path` and renames it into place.
+/// Readers never see a partial file, and concurrent writers never share a temporary file.
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    fs::create_dir_all(dir)?;
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let tmp = dir.join(format!(
+        ".{}.{}-{}.tmp",
+        file_name,
+        process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_errors() {
+        let dir = env::temp_dir().join(format!("aoc-input-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let read = |name: &str, contents: Option<&[u8]>| {
+            let path = dir.join(name);
+            if let Some(contents) = contents {
+                fs::write(&path, contents).unwrap();
+            }
+            read_path(&path)
+        };
+
+        assert!(matches!(read("missing.txt", None), Err(InputError::Missing(_))));
+        assert!(matches!(read("empty.txt", Some(b"")), Err(InputError::Empty(_))));
+        assert!(matches!(read("binary.txt", Some(&[0xff, 0xfe])), Err(InputError::NotUtf8(_))));
+        assert_eq!(read("ok.txt", Some(b"1\n")).unwrap(), "1\n");
+
+        write_atomic(&dir.join("nested").join("ok.txt"), "2\n").unwrap();
+        assert_eq!(read("nested/ok.txt", None).unwrap(), "2\n");
+        assert_eq!(fs::read_dir(dir.join("nested")).unwrap().count(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_overrides_only_personal_inputs() {
+        let options = InputOptions {
+            input: Some("-".to_string()),
+            verbose: false,
+        };
+        assert_eq!(resolve("inputs", 2022, 5, &options), Source::Stdin);
+        assert_eq!(
+            resolve("examples", 2022, 5, &options),
+            Source::File(project_dir().join("src/examples/2022/05.txt"))
+        );
+
+        let options = InputOptions {
+            input: Some("/tmp/day5.txt".to_string()),
+            verbose: false,
+        };
+        assert_eq!(
+            resolve("inputs", 2022, 5, &options),
+            Source::File(PathBuf::from("/tmp/day5.txt"))
+        );
+    }
+
+    #[test]
+    fn test_input_dir_moves_personal_inputs() {
+        env::set_var(INPUT_DIR_VAR, "/tmp/aoc-inputs");
+        let inputs = input_path("inputs", 2022, 5);
+        let examples = input_path("examples", 2022, 5);
+        env::remove_var(INPUT_DIR_VAR);
+
+        assert_eq!(inputs, PathBuf::from("/tmp/aoc-inputs/2022/05.txt"));
+        assert_eq!(examples, project_dir().join("src/examples/2022/05.txt"));
+        assert_eq!(
+            input_path("inputs", 2022, 5),
+            project_dir().join("src/inputs/2022/05.txt")
+        );
+    }
+}
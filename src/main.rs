@@ -1,44 +1,530 @@
-#![feature(trait_alias)]
+#![feature(iter_next_chunk)]
 
+use advent_of_code::answers::{self, Verdict};
+use advent_of_code::Answer;
+use advent_of_code::bench::BenchConfig;
+use advent_of_code::client::Client;
+use advent_of_code::history::{self, Run};
+use advent_of_code::input::{self, InputOptions};
+use advent_of_code::report::{self, Format, Record};
+use advent_of_code::solution::{PartResult, Registry};
+use advent_of_code::submit::{self, Outcome, Refusal, Submission};
 use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use std::ffi::OsString;
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use std::{error, io, process, thread};
+
+/// Pulls every day's `src/solutions/<year>/NN.rs` into its year's module and registers its `Solution`.
+/// Used by `src/solutions/<year>/mod.rs`, where `cargo scaffold` adds a line for every new day.
+macro_rules! days {
+    ($($module:ident => $path:literal),* $(,)?) => {
+        $(
+            #[allow(dead_code, unused_attributes)]
+            #[path = $path]
+            mod $module;
+        )*
+
+        #[allow(unused_variables)]
+        pub fn register(registry: &mut Registry) {
+            $(registry.register::<$module::Day>();)*
+        }
+    };
+}
+
+/// Pulls in every year's `src/solutions/<year>/mod.rs`. `cargo scaffold` adds a line for every new year.
+macro_rules! years {
+    ($($module:ident => $path:literal),* $(,)?) => {
+        $(
+            #[path = $path]
+            mod $module;
+        )*
+
+        fn registry() -> Registry {
+            let mut registry = Registry::new();
+            $($module::register(&mut registry);)*
+            registry
+        }
+    };
+}
+
+years! {
+    y2022 => "solutions/2022/mod.rs",
+}
+
+/// `cargo solve [<year>] <day>` runs a single day, `cargo all [--year <year>]` every day of a year.
+/// `cargo submit [<year>] <day> <part>` runs a single day and submits the answer of one part.
+/// `cargo all --submit` offers to submit every answer that was not accepted yet.
+enum Target {
+    Day(u16, u8),
+    Year(u16),
+    Submit(u16, u8, u8),
+}
+
+struct Args {
+    target: Target,
+    format: Format,
+    bench: Option<BenchConfig>,
+    verify: bool,
+    compare: bool,
+    baseline: Option<String>,
+    save_baseline: Option<String>,
+    threshold: f64,
+    history: Option<PathBuf>,
+    input: InputOptions,
+    submit: bool,
+    yes: bool,
+}
+
+fn invalid(cause: impl Into<String>) -> Box<dyn error::Error> {
+    cause.into().into()
+}
+
+fn parse_free<T: FromStr>(value: &str, what: &str) -> Result<T, Box<dyn error::Error>> {
+    value
+        .parse()
+        .map_err(|_| invalid(format!("\"{}\" is not a valid {}", value, what)))
+}
+
+/// Interprets the positional arguments: none, `<day>` or `<year> <day>`.
+/// With `--submit`, a day is followed by the `<part>` to submit.
+fn parse_target(
+    free: Vec<OsString>,
+    year: Option<u16>,
+    submit: bool,
+) -> Result<Target, Box<dyn error::Error>> {
+    let mut free = free
+        .into_iter()
+        .map(|arg| arg.into_string().map_err(|_| pico_args::Error::NonUtf8Argument))
+        .collect::<Result<Vec<_>, _>>()?;
+    if free.iter().any(|arg| arg == "--release" || arg == "-r") {
+        return Err(invalid(
+            "--release is a cargo flag, use `cargo solve --release <year> <day>` or `cargo run --release -- [options]`",
+        ));
+    }
+    if let Some(flag) = free.iter().find(|arg| arg.starts_with('-')) {
+        return Err(invalid(format!("unknown option {}", flag)));
+    }
+
+    let default_year = || {
+        year.or_else(input::default_year).ok_or_else(|| {
+            invalid(format!("no year given, pass --year or set {}", input::YEAR_VAR))
+        })
+    };
+    let day = |value: &str| match parse_free(value, "day")? {
+        day @ 1..=25 => Ok(day),
+        _ => Err(invalid(format!("day {} is not between 1 and 25", value))),
+    };
+    let part = match submit {
+        true if free.is_empty() => None,
+        true if free.len() < 2 => return Err(invalid("--submit needs a day and a part")),
+        true => match free.pop().as_deref().map(|part| parse_free(part, "part")) {
+            Some(Ok(part @ 1..=2)) => Some(part),
+            _ => return Err(invalid("the part to submit is either 1 or 2")),
+        },
+        false => None,
+    };
+    let target = match free.as_slice() {
+        [] => Target::Year(default_year()?),
+        [d] => Target::Day(default_year()?, day(d)?),
+        [_, _] if year.is_some() => {
+            return Err(invalid("pass the year either with --year or before the day"))
+        }
+        [y, d] => Target::Day(parse_free(y, "year")?, day(d)?),
+        [_, _, rest @ ..] => {
+            return Err(invalid(format!("unexpected arguments {}", rest.join(" "))))
+        }
+    };
+    match (target, part) {
+        (Target::Day(year, day), Some(part)) => Ok(Target::Submit(year, day, part)),
+        (target, _) => Ok(target),
+    }
+}
+
+fn parse_args() -> Result<Args, Box<dyn error::Error>> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = args.opt_value_from_str(["-y", "--year"])?;
+    let submit = args.contains("--submit");
+    let parsed = Args {
+        format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+        bench: BenchConfig::from_args(&mut args)?,
+        verify: args.contains("--verify"),
+        compare: args.contains("--compare"),
+        baseline: args.opt_value_from_str("--baseline")?,
+        save_baseline: args.opt_value_from_str("--save-baseline")?,
+        threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10_f64),
+        history: args.opt_value_from_str("--history")?,
+        input: InputOptions::from_args(&mut args)?,
+        submit,
+        yes: args.contains("--yes"),
+        // positional arguments can only be read once every option was taken.
+        target: parse_target(args.finish(), year, submit)?,
+    };
+
+    match parsed.target {
+        Target::Year(_) if parsed.input.input.is_some() => {
+            Err(invalid("--input only works when solving a single day"))
+        }
+        Target::Day(..) | Target::Submit(..)
+            if parsed.compare
+                || parsed.baseline.is_some()
+                || parsed.save_baseline.is_some()
+                || parsed.history.is_some() =>
+        {
+            Err(invalid("benchmark history is only recorded by `cargo all`"))
+        }
+        _ if (parsed.compare || parsed.save_baseline.is_some()) && parsed.bench.is_none() => Err(
+            invalid("--compare and --save-baseline need --bench, a single timing is too noisy"),
+        ),
+        _ => Ok(parsed),
+    }
+}
+
+/// Messages go to stderr when stdout carries a report.
+fn messages(format: Format) -> Box<dyn io::Write> {
+    if format == Format::Text {
+        Box::new(io::stdout())
+    } else {
+        Box::new(io::stderr())
+    }
+}
+
+/// Appends this run's timings to the history file and, with `--compare`, checks them against an earlier run.
+/// Returns whether any part regressed.
+fn track_history(args: &Args, records: &[Record]) -> Result<bool, Box<dyn error::Error>> {
+    let path = match &args.history {
+        Some(path) => path.clone(),
+        None => history::default_path(),
+    };
+    let runs = history::load(&path)?;
+    let current = Run::from_records(records, args.save_baseline.clone());
+    history::append(&path, &current)?;
+
+    if !args.compare {
+        return Ok(false);
+    }
+
+    let mut out = messages(args.format);
+    match history::find_baseline(&runs, &current, args.baseline.as_deref()) {
+        Some(baseline) => {
+            let changes = history::compare(baseline, &current, args.threshold);
+            history::print_changes(baseline, &changes, &mut out)?;
+            Ok(changes.iter().any(|change| change.regressed))
+        }
+        None => {
+            writeln!(out, "No earlier run in \"{}\" to compare against.", path.display())?;
+            Ok(false)
+        }
+    }
+}
+
+/// Sends the answer of one part unless earlier submissions rule it out, and records the verdict.
+/// Accepted answers are added to `answers/<year>/NN.toml`. With `wait`, a pending cooldown is waited out
+/// instead of giving up. Returns whether the answer was accepted.
+fn submit_answer(
+    year: u16,
+    day: u8,
+    result: &PartResult,
+    wait: bool,
+    out: &mut dyn io::Write,
+) -> Result<bool, Box<dyn error::Error>> {
+    // drawn letters are submitted as the letters they read as.
+    let answer = match result.outcome.as_ref().map(Answer::read_letters) {
+        Ok(Ok(answer)) => answer,
+        Ok(Err(e)) => {
+            return Err(invalid(format!(
+                "could not read the letters drawn by part {}: {}",
+                result.part, e
+            )))
+        }
+        Err(_) => return Err(invalid(format!("part {} has no answer to submit", result.part))),
+    };
+    let path = submit::default_path();
+    loop {
+        let submissions = submit::load(&path)?;
+        match submit::check(&submissions, year, day, result.part, &answer, submit::now()) {
+            Some(Refusal::Wait(seconds)) if wait => {
+                writeln!(out, "Waiting {}s for the cooldown...", seconds)?;
+                thread::sleep(Duration::from_secs(seconds));
+            }
+            Some(refusal) => {
+                writeln!(out, "Not submitting {}: {}.", answer, refusal)?;
+                return Ok(false);
+            }
+            None => break,
+        }
+    }
+
+    let client = Client::from_env()?;
+    writeln!(
+        out,
+        "Submitting {} for {} day {:02} part {}...",
+        answer, year, day, result.part
+    )?;
+    let page = client.submit(year, day, result.part, &answer.to_string())?;
+    let submission = Submission::new(year, day, result.part, &answer, &page);
+    submit::append(&path, &submission)?;
+
+    writeln!(out, "{}{}{}", ANSI_BOLD, submission.outcome, ANSI_RESET)?;
+    if submission.outcome == Outcome::Unknown {
+        writeln!(out, "{}", submit::message(&page))?;
+    }
+    if let Some(wait) = submission.wait_s {
+        writeln!(out, "Wait {}s before submitting again.", wait)?;
+    }
+    if submission.outcome != Outcome::Correct {
+        return Ok(false);
+    }
+    answers::record(year, day, result.part, &answer)?;
+    writeln!(
+        out,
+        "Recorded the answer in answers/{}/{:02}.toml.",
+        year, day
+    )?;
+    Ok(true)
+}
+
+/// Asks whether to submit each solved part that has no accepted answer, one after another.
+/// Returns whether every submission went through without an error.
+fn submit_pending(year: u16, pending: &[(u8, PartResult)], yes: bool, out: &mut dyn io::Write) -> bool {
+    let submissions = match submit::load(&submit::default_path()) {
+        Ok(submissions) => submissions,
+        Err(e) => {
+            eprintln!("Failed to read the submission history: {}", e);
+            return false;
+        }
+    };
+    // parts accepted before the answer store was kept up to date are not offered again.
+    let pending: Vec<_> = pending
+        .iter()
+        .filter(|(day, result)| {
+            !submissions.iter().any(|s| {
+                s.year == year && s.day == *day && s.part == result.part && s.outcome == Outcome::Correct
+            })
+        })
+        .collect();
+    if pending.is_empty() {
+        return true;
+    }
+    if !yes && !io::stdin().is_terminal() {
+        eprintln!(
+            "{} answers were not submitted, pass --yes to submit them without asking.",
+            pending.len()
+        );
+        return true;
+    }
+
+    let mut ok = true;
+    for (day, result) in pending {
+        let answer = match result.outcome.as_ref().map(Answer::read_letters) {
+            Ok(Ok(answer)) => answer.to_string(),
+            _ => String::new(),
+        };
+        let question = format!(
+            "Day {:02} Part {} has no accepted answer yet. Submit {}?",
+            day, result.part, answer
+        );
+        if !yes && !confirm(&question, out) {
+            continue;
+        }
+        if let Err(e) = submit_answer(year, *day, result, true, out) {
+            eprintln!("Failed to submit the answer: {}", e);
+            ok = false;
+        }
+    }
+    ok
+}
+
+/// Anything but `y` or `yes` is a no.
+fn confirm(question: &str, out: &mut dyn io::Write) -> bool {
+    let mut line = String::new();
+    write!(out, "{} [y/N] ", question)
+        .and_then(|_| out.flush())
+        .and_then(|_| io::stdin().read_line(&mut line))
+        .is_ok_and(|_| matches!(line.trim().to_lowercase().as_str(), "y" | "yes"))
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    let text = args.format == Format::Text;
+    let registry = registry();
+    let mut records = vec![];
+    let mut pending = vec![];
+    let mut failed = false;
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+    let (year, days, all) = match args.target {
+        Target::Day(year, day) | Target::Submit(year, day, _) => (year, day..=day, false),
+        Target::Year(year) => (year, 1..=25, true),
+    };
+    if text && all {
+        println!("{}🎄 Advent of Code {} 🎄{}", ANSI_BOLD, year, ANSI_RESET);
+    }
 
+    for day in days {
+        if text && all {
             println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
+            println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
             println!("----------");
+        }
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+        let entry = match registry.get(year, day) {
+            Some(entry) => entry,
+            None => {
+                if text {
+                    println!("Not solved.");
+                }
+                if !all {
+                    eprintln!(
+                        "Day {:02} of {} is not registered in src/solutions/{}/mod.rs.",
+                        day, year, year
+                    );
+                    failed = true;
+                }
+                records.extend([1, 2].map(|part| Record::not_scaffolded(year, day, part)));
+                continue;
+            }
+        };
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
+        match input::load("inputs", year, day, &args.input) {
+            Ok(input) => {
+                let results = entry.run(&input);
+                let stats = args
+                    .bench
+                    .as_ref()
+                    .filter(|_| results.iter().all(|result| !result.panicked))
+                    .map(|config| entry.bench(&input, config));
+                let expected = if args.verify || (all && args.submit) {
+                    match answers::load(year, day) {
+                        Ok(answers) => Some(answers.input),
+                        Err(e) => {
+                            eprintln!("Failed to read answers for {} day {:02}: {}", year, day, e);
+                            process::exit(1);
+                        }
+                    }
                 } else {
-                    output.trim()
+                    None
+                };
+                for (i, result) in results.iter().enumerate() {
+                    let mut record = Record::from_result(year, day, result);
+                    if text {
+                        result.print();
+                    }
+                    if let (true, Some(expected)) = (args.verify, &expected) {
+                        let verdict = Verdict::new(expected.part(result.part), &result.outcome);
+                        if text {
+                            println!("{}", verdict);
+                        }
+                        failed |= matches!(verdict, Verdict::Fail { .. });
+                        record = record.with_verdict(&verdict);
+                    }
+                    if let Some(stats) = &stats {
+                        if text {
+                            stats[i].print();
+                        }
+                        record = record.with_stats(&stats[i]);
+                    }
+                    if let (true, Some(expected)) = (all && args.submit, &expected) {
+                        if result.outcome.is_ok() && expected.part(result.part).is_none() {
+                            pending.push((day, result.clone()));
+                        }
+                    }
+                    records.push(record);
                 }
-            );
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+                if let Target::Submit(_, _, part) = args.target {
+                    let result = &results[usize::from(part) - 1];
+                    match submit_answer(year, day, result, false, &mut messages(args.format)) {
+                        Ok(accepted) => failed |= !accepted,
+                        Err(e) => {
+                            eprintln!("Failed to submit the answer: {}", e);
+                            failed = true;
+                        }
+                    }
+                }
             }
-        })
-        .sum();
+            Err(e) => {
+                if text {
+                    println!("No input: {}", e);
+                }
+                failed |= !all;
+                records.extend([1, 2].map(|part| Record::no_input(year, day, part, e.to_string())));
+            }
+        }
+    }
+
+    if !text {
+        if let Err(e) = report::write_records(args.format, &records, io::stdout()) {
+            eprintln!("Failed to write report: {}", e);
+            process::exit(1);
+        }
+    }
+    if !all {
+        process::exit(i32::from(failed));
+    }
+
+    if text {
+        let total: f64 = records
+            .iter()
+            .filter_map(|record| record.median_ns.or(record.elapsed_ns))
+            .map(|ns| ns as f64 / 1_000_000_f64)
+            .sum();
+        println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+        );
+    }
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    if args.submit && !submit_pending(year, &pending, args.yes, &mut messages(args.format)) {
+        failed = true;
+    }
+
+    match track_history(&args, &records) {
+        Ok(regressed) => {
+            if regressed || failed {
+                process::exit(1);
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to update benchmark history: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::generate;
+
+    #[test]
+    fn test_solutions_solve_generated_inputs() {
+        let registry = registry();
+        for generator in generate::GENERATORS {
+            let Some(entry) = registry.get(generator.year, generator.day) else {
+                continue;
+            };
+            for seed in 0..3 {
+                let input = generator.generate(generator.default_size, seed);
+                for result in entry.run(&input) {
+                    assert!(
+                        result.outcome.is_ok(),
+                        "{} day {:02} part {} with seed {}: {:?}",
+                        generator.year,
+                        generator.day,
+                        result.part,
+                        seed,
+                        result.outcome
+                    );
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,388 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::path::PathBuf;
+
+use crate::input::{self, InputError};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Where `cargo download` caches a day's puzzle description, as Markdown.
+pub fn path(year: u16, day: u8) -> PathBuf {
+    input::project_dir()
+        .join("src")
+        .join("puzzles")
+        .join(year.to_string())
+        .join(format!("{:02}.md", day))
+}
+
+pub fn load(year: u16, day: u8) -> Result<String, InputError> {
+    input::read_path(&path(year, day))
+}
+
+/// The contents of the first code block, which is the example input of almost every puzzle.
+/// Understands both Markdown fences and the `<pre><code>` blocks of the puzzle's HTML.
+pub fn first_example(description: &str) -> Option<String> {
+    let block = match description.find("<pre><code>") {
+        Some(start) if description.find("```").is_none_or(|fence| start < fence) => {
+            let rest = &description[start + "<pre><code>".len()..];
+            unescape_html(&strip_tags(&rest[..rest.find("</code></pre>")?]))
+        }
+        _ => {
+            let start = description.find("```")?;
+            let rest = &description[start..];
+            // skip the rest of the opening fence line, which may name a language.
+            let rest = &rest[rest.find('\n')? + 1..];
+            rest[..rest.find("```")?].to_string()
+        }
+    };
+
+    let example = block.trim_end_matches(['\n', ' ']);
+    if example.is_empty() {
+        return None;
+    }
+    Some(format!("{}\n", example))
+}
+
+pub(crate) fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn unescape_html(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Writes the first example of the cached description to `src/examples/<year>/NN.txt`,
+/// unless that file already has content. Returns whether an example was written.
+pub fn write_example(year: u16, day: u8) -> Result<bool, Box<dyn std::error::Error>> {
+    let example_path = input::input_path("examples", year, day);
+    match input::read_path(&example_path) {
+        Err(e) if e.is_absent() => {}
+        Err(e) => return Err(e.into()),
+        Ok(_) => return Ok(false),
+    }
+    let example = match load(year, day) {
+        Ok(description) => first_example(&description),
+        Err(e) if e.is_absent() => None,
+        Err(e) => return Err(e.into()),
+    };
+    match example {
+        Some(example) => {
+            input::write_atomic(&example_path, &example)?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Converts the `<article>` elements of a puzzle page, one per unlocked part, to the Markdown that is cached.
+pub fn from_html(page: &str) -> String {
+    let mut out = String::new();
+    let mut rest = page;
+    while let Some(start) = rest.find("<article") {
+        let body = match rest[start..].find('>') {
+            Some(end) => &rest[start + end + 1..],
+            None => break,
+        };
+        let end = body.find("</article>").unwrap_or(body.len());
+        article_to_markdown(&body[..end], &mut out);
+        rest = &body[end..];
+    }
+    let trimmed = out.trim_end().len();
+    out.truncate(trimmed);
+    out.push('\n');
+    out
+}
+
+fn article_to_markdown(html: &str, out: &mut String) {
+    let mut in_pre = false;
+    let mut links: Vec<String> = vec![];
+    let mut rest = html;
+    while !rest.is_empty() {
+        let (text, tag) = match rest.find('<') {
+            Some(0) => ("", rest.find('>').map(|end| &rest[1..end])),
+            Some(start) => (&rest[..start], None),
+            None => (rest, None),
+        };
+        if !text.is_empty() {
+            rest = &rest[text.len()..];
+            let text = unescape_html(text);
+            if in_pre {
+                out.push_str(&text);
+                continue;
+            }
+            // HTML collapses whitespace, Markdown needs it on a single line.
+            if text.starts_with(char::is_whitespace) && !out.is_empty() && !out.ends_with([' ', '\n']) {
+                out.push(' ');
+            }
+            let words = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            for c in words.chars() {
+                if matches!(c, '\\' | '*' | '`' | '[' | ']') {
+                    out.push('\\');
+                }
+                out.push(c);
+            }
+            if text.ends_with(char::is_whitespace) && !words.is_empty() {
+                out.push(' ');
+            }
+            continue;
+        }
+        let tag = match tag {
+            Some(tag) => tag,
+            None => break,
+        };
+        rest = &rest[tag.len() + 2..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+        match (name, closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p", true) => out.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => out.push('`'),
+            ("em", _) if !in_pre => out.push('*'),
+            ("a", false) => {
+                links.push(attribute(tag, "href").unwrap_or_default());
+                out.push('[');
+            }
+            ("a", true) => {
+                out.push_str(&format!("]({})", links.pop().unwrap_or_default()));
+            }
+            ("li", false) => out.push_str("* "),
+            ("li", true) => out.push('\n'),
+            ("ul", true) => out.push('\n'),
+            _ => {}
+        }
+    }
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{}=\"", name))? + name.len() + 2;
+    let end = tag[start..].find('"')?;
+    Some(unescape_html(&tag[start..start + end]))
+}
+
+/// Formats a cached description for the terminal: headings and emphasis in bold, code blocks indented,
+/// link targets dropped and paragraphs wrapped to `width` columns.
+/// The answer form and share links at the end of the page are left out.
+pub fn render(description: &str, width: usize) -> String {
+    let lines: Vec<&str> = description.lines().collect();
+    let mut out = String::new();
+    let mut in_code = false;
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        i += 1;
+
+        if line.starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            if !line.is_empty() {
+                out.push_str("    ");
+                out.push_str(line);
+            }
+            out.push('\n');
+            continue;
+        }
+        if line == "Answer:" || line.starts_with("You can also [Share") {
+            continue;
+        }
+
+        let underlined = lines
+            .get(i)
+            .is_some_and(|next| next.len() >= 3 && next.chars().all(|c| c == '-' || c == '='));
+        if underlined || line.starts_with('#') {
+            if underlined {
+                i += 1;
+            }
+            let heading = inline(line.trim_start_matches('#').trim());
+            out.push_str(&format!("{}{}{}\n", ANSI_BOLD, heading, ANSI_RESET));
+        } else if let Some(item) = line.strip_prefix("* ") {
+            out.push_str(&wrap(&inline(item), width, "  • ", "    "));
+        } else if line.trim().is_empty() {
+            out.push('\n');
+        } else {
+            out.push_str(&wrap(&inline(line), width, "", ""));
+        }
+    }
+    out
+}
+
+/// Resolves the inline Markdown of one line: `*emphasis*`, `` `code` ``, `[links](...)` and `\` escapes.
+fn inline(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let (mut bold, mut code) = (false, false);
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => out.extend(chars.next()),
+            '`' => {
+                code = !code;
+                out.push_str(if code { ANSI_ITALIC } else { ANSI_RESET });
+                if bold && !code {
+                    out.push_str(ANSI_BOLD);
+                }
+            }
+            '*' => {
+                bold = !bold;
+                out.push_str(if bold { ANSI_BOLD } else { ANSI_RESET });
+                if code && !bold {
+                    out.push_str(ANSI_ITALIC);
+                }
+            }
+            '[' if !code => {}
+            ']' if !code && chars.peek() == Some(&'(') => {
+                let mut depth = 0;
+                for c in chars.by_ref() {
+                    match c {
+                        '(' => depth += 1,
+                        ')' if depth == 1 => break,
+                        ')' => depth -= 1,
+                        _ => {}
+                    }
+                }
+            }
+            ']' if !code => {}
+            c => out.push(c),
+        }
+    }
+    if bold || code {
+        out.push_str(ANSI_RESET);
+    }
+    out
+}
+
+/// Number of columns `text` takes up, ignoring ANSI escape sequences.
+fn visible_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut in_escape = false;
+    for c in text.chars() {
+        match c {
+            '\x1b' => in_escape = true,
+            'm' if in_escape => in_escape = false,
+            _ if !in_escape => width += 1,
+            _ => {}
+        }
+    }
+    width
+}
+
+fn wrap(text: &str, width: usize, first_indent: &str, indent: &str) -> String {
+    let mut out = String::from(first_indent);
+    let mut column = visible_width(first_indent);
+    let mut line_has_word = false;
+    for word in text.split(' ').filter(|word| !word.is_empty()) {
+        let word_width = visible_width(word);
+        if line_has_word && column + 1 + word_width > width {
+            out.push('\n');
+            out.push_str(indent);
+            column = visible_width(indent);
+        } else if line_has_word {
+            out.push(' ');
+            column += 1;
+        }
+        out.push_str(word);
+        column += word_width;
+        line_has_word = true;
+    }
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DESCRIPTION: &str = "\\--- Day 8: Treetop Tree House ---\n\
+                               ----------\n\
+                               \n\
+                               A [tree house](https://example.com) example:\n\
+                               \n\
+                               ```\n\
+                               30373\n\
+                               25512\n\
+                               \n\
+                               ```\n\
+                               \n\
+                               * a total of `*21*` trees\n\
+                               \n\
+                               Answer:\n";
+
+    #[test]
+    fn test_first_example() {
+        assert_eq!(first_example(DESCRIPTION), Some("30373\n25512\n".to_string()));
+        assert_eq!(
+            first_example("<p>For example:</p>\n<pre><code>a &lt; <em>b</em>\n</code></pre>"),
+            Some("a < b\n".to_string())
+        );
+        assert_eq!(first_example("no example today"), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let rendered = render(DESCRIPTION, 80);
+        assert_eq!(
+            rendered,
+            format!(
+                "{b}--- Day 8: Treetop Tree House ---{r}\n\nA tree house example:\n\n    30373\n    25512\n\n\n  • a total of {i}{b}21{r}{i}{r} trees\n\n",
+                b = ANSI_BOLD,
+                i = ANSI_ITALIC,
+                r = ANSI_RESET
+            )
+        );
+    }
+
+    #[test]
+    fn test_from_html() {
+        let page = "<main>\n<article class=\"day-desc\"><h2>--- Day 8: Treetop Tree House ---</h2>\
+                    <p>A <a href=\"https://example.com\">tree house</a>, for example:</p>\n\
+                    <pre><code>30373\n<em>2</em>5512\n</code></pre>\n\
+                    <ul><li>a total of <code><em>21</em></code> trees (<code>a*b</code>)</li></ul>\n\
+                    </article>\n<p>Answer: <input/></p></main>";
+        let markdown = from_html(page);
+        assert_eq!(
+            markdown,
+            "## --- Day 8: Treetop Tree House ---\n\n\
+             A [tree house](https://example.com), for example:\n\n\
+             ```\n30373\n25512\n```\n\n\
+             * a total of `*21*` trees (`a\\*b`)\n"
+        );
+        assert_eq!(first_example(&markdown), Some("30373\n25512\n".to_string()));
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(wrap("aaa bbb ccc", 7, "", ""), "aaa bbb\nccc\n");
+        assert_eq!(wrap("aaa bbb", 6, "- ", "  "), "- aaa\n  bbb\n");
+    }
+}
@@ -0,0 +1,226 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::time::Duration;
+
+use crate::answer::Answer;
+use crate::answers::Answers;
+use crate::history::Run;
+use crate::submit::{Outcome, Submission};
+
+/// `cargo readme` replaces everything between these two lines of README.md.
+pub const START_MARKER: &str = "<!--- advent_of_code table --->";
+pub const END_MARKER: &str = "<!--- advent_of_code table end --->";
+
+/// What the table shows for one day.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Row {
+    pub day: u8,
+    pub answers: [Option<String>; 2],
+    pub median_ns: [Option<u64>; 2],
+}
+
+impl Row {
+    /// Collects the accepted answers from the answers file and the submission history,
+    /// and the latest timings of `profile` from the benchmark history.
+    pub fn new(
+        year: u16,
+        day: u8,
+        answers: &Answers,
+        submissions: &[Submission],
+        runs: &[Run],
+        profile: &str,
+    ) -> Self {
+        let mut row = Row {
+            day,
+            ..Row::default()
+        };
+        for part in [1, 2] {
+            let i = usize::from(part) - 1;
+            row.answers[i] = answers.input.part(part).map(Answer::to_string).or_else(|| {
+                submissions
+                    .iter()
+                    .find(|s| {
+                        (s.year, s.day, s.part) == (year, day, part)
+                            && s.outcome == Outcome::Correct
+                    })
+                    .map(|s| s.answer.to_string())
+            });
+            row.median_ns[i] = latest_median(runs, profile, year, day, part);
+        }
+        row
+    }
+
+    pub fn stars(&self) -> usize {
+        self.answers
+            .iter()
+            .filter(|answer| answer.is_some())
+            .count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stars() == 0 && self.median_ns.iter().all(Option::is_none)
+    }
+}
+
+/// The profile whose timings the table shows for `year`. Release runs win over debug runs,
+/// as debug timings say little about a solution. `None` if no run timed the year.
+pub fn profile(runs: &[Run], year: u16) -> Option<&str> {
+    let timed = |run: &&Run| run.samples.iter().any(|s| s.year == year);
+    if runs
+        .iter()
+        .filter(timed)
+        .any(|run| run.profile == "release")
+    {
+        Some("release")
+    } else {
+        runs.iter()
+            .rev()
+            .find(timed)
+            .map(|run| run.profile.as_str())
+    }
+}
+
+/// The median of the newest run of `profile` that timed this part.
+fn latest_median(runs: &[Run], profile: &str, year: u16, day: u8, part: u8) -> Option<u64> {
+    runs.iter()
+        .rev()
+        .filter(|run| run.profile == profile)
+        .find_map(|run| {
+            run.samples
+                .iter()
+                .find(|s| (s.year, s.day, s.part) == (year, day, part))
+                .map(|s| s.median_ns)
+        })
+}
+
+fn format_ns(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
+
+/// Renders the Markdown table, followed by the total stars and time. Answers are only shown with `show_answers`.
+pub fn table(year: u16, rows: &[Row], show_answers: bool) -> String {
+    let mut out =
+        String::from("| Day | Stars | Part 1 | Part 2 |\n| :---: | :---: | ---: | ---: |\n");
+    for row in rows {
+        let cells = [0, 1].map(|i| {
+            let time = row.median_ns[i].map(format_ns);
+            match (&row.answers[i], time) {
+                (Some(answer), Some(time)) if show_answers => format!("`{}` ({})", answer, time),
+                (Some(answer), None) if show_answers => format!("`{}`", answer),
+                (_, Some(time)) => time,
+                (_, None) => "-".to_string(),
+            }
+        });
+        out.push_str(&format!(
+            "| [Day {}](https://adventofcode.com/{}/day/{}) | {} | {} | {} |\n",
+            row.day,
+            year,
+            row.day,
+            "⭐".repeat(row.stars()),
+            cells[0],
+            cells[1]
+        ));
+    }
+    let stars: usize = rows.iter().map(Row::stars).sum();
+    let total: u64 = rows.iter().flat_map(|row| row.median_ns).flatten().sum();
+    out.push_str(&format!("\n**Total: {}⭐, {}**\n", stars, format_ns(total)));
+    out
+}
+
+/// Puts `table` between the markers of `readme`. `None` if the markers are missing.
+pub fn replace_table(readme: &str, table: &str) -> Option<String> {
+    let start = readme.find(START_MARKER)? + START_MARKER.len();
+    let end = start + readme[start..].find(END_MARKER)?;
+    Some(format!(
+        "{}\n\n{}\n{}",
+        &readme[..start],
+        table,
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::Sample;
+
+    fn run(profile: &str, median_ns: u64) -> Run {
+        Run {
+            commit: None,
+            timestamp: 1,
+            profile: profile.to_string(),
+            baseline: None,
+            benched: true,
+            samples: vec![Sample {
+                year: 2022,
+                day: 1,
+                part: 1,
+                commit: None,
+                timestamp: 1,
+                profile: profile.to_string(),
+                baseline: None,
+                median_ns,
+                samples: 1,
+                benched: true,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_rows_and_table() {
+        let answers: Answers = toml::from_str("[input]\npart_one = 24000\n").unwrap();
+        let submissions = vec![Submission {
+            year: 2022,
+            day: 1,
+            part: 2,
+            answer: Answer::Int(45000),
+            outcome: Outcome::Correct,
+            timestamp: 1,
+            wait_s: None,
+        }];
+        let runs = vec![run("release", 1_500), run("debug", 90_000)];
+
+        assert_eq!(profile(&runs, 2022), Some("release"));
+        assert_eq!(profile(&runs[1..], 2022), Some("debug"));
+        assert_eq!(profile(&runs, 2021), None);
+
+        let row = Row::new(2022, 1, &answers, &submissions, &runs, "release");
+        assert_eq!(
+            row.answers,
+            [Some("24000".to_string()), Some("45000".to_string())]
+        );
+        assert_eq!(row.median_ns, [Some(1_500), None]);
+        assert!(Row::new(2022, 2, &Answers::default(), &submissions, &runs, "release").is_empty());
+
+        let mut debug = run("debug", 90_000);
+        debug.samples[0].part = 2;
+        let runs = vec![run("release", 1_500), debug];
+        let row = Row::new(2022, 1, &answers, &submissions, &runs, "release");
+        assert_eq!(row.median_ns, [Some(1_500), None]);
+
+        let header = "| Day | Stars | Part 1 | Part 2 |\n| :---: | :---: | ---: | ---: |\n";
+        assert_eq!(
+            table(2022, std::slice::from_ref(&row), false),
+            format!(
+                "{}| [Day 1](https://adventofcode.com/2022/day/1) | ⭐⭐ | 1.50µs | - |\n\n**Total: 2⭐, 1.50µs**\n",
+                header
+            )
+        );
+        assert!(table(2022, &[row], true).contains("| `24000` (1.50µs) | `45000` |"));
+    }
+
+    #[test]
+    fn test_replace_table() {
+        let readme = format!("# AoC\n{}\nold\n{}\nrest\n", START_MARKER, END_MARKER);
+        assert_eq!(
+            replace_table(&readme, "| new |\n").unwrap(),
+            format!(
+                "# AoC\n{}\n\n| new |\n\n{}\nrest\n",
+                START_MARKER, END_MARKER
+            )
+        );
+        assert_eq!(replace_table("# AoC\n", "| new |\n"), None);
+    }
+}
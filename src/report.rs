@@ -0,0 +1,239 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::error;
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::answer::Answer;
+use crate::answers::Verdict;
+use crate::bench::Stats;
+use crate::solution::PartResult;
+
+/// Output format of `cargo all`, selected with `--format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone)]
+pub struct UnknownFormatError(String);
+
+impl fmt::Display for UnknownFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "unknown format \"{}\", expected one of: text, json, csv",
+            self.0
+        ))
+    }
+}
+
+impl error::Error for UnknownFormatError {}
+
+impl FromStr for Format {
+    type Err = UnknownFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            other => Err(UnknownFormatError(other.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Solved,
+    Error,
+    NoInput,
+    #[default]
+    NotScaffolded,
+}
+
+/// One row of the machine-readable report: a single part of a single day of a single year.
+/// The statistics columns are only filled in when running with `--bench`, the verification columns with `--verify`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<Answer>,
+    pub error: Option<String>,
+    pub elapsed_ns: Option<u64>,
+    pub samples: Option<usize>,
+    pub min_ns: Option<u64>,
+    pub median_ns: Option<u64>,
+    pub mean_ns: Option<u64>,
+    pub stddev_ns: Option<u64>,
+    pub p95_ns: Option<u64>,
+    pub verified: Option<&'static str>,
+    pub expected: Option<Answer>,
+}
+
+impl Record {
+    pub fn from_result(year: u16, day: u8, result: &PartResult) -> Self {
+        let (status, answer, error) = match &result.outcome {
+            Ok(answer) => (Status::Solved, Some(answer.clone()), None),
+            Err(e) => (Status::Error, None, Some(e.clone())),
+        };
+        Record {
+            year,
+            day,
+            part: result.part,
+            status,
+            answer,
+            error,
+            elapsed_ns: Some(result.elapsed.as_nanos() as u64),
+            ..Default::default()
+        }
+    }
+
+    pub fn no_input(year: u16, day: u8, part: u8, reason: String) -> Self {
+        Record {
+            year,
+            day,
+            part,
+            status: Status::NoInput,
+            error: Some(reason),
+            ..Default::default()
+        }
+    }
+
+    pub fn not_scaffolded(year: u16, day: u8, part: u8) -> Self {
+        Record {
+            year,
+            day,
+            part,
+            status: Status::NotScaffolded,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_stats(self, stats: &Stats) -> Self {
+        let ns = |d: std::time::Duration| Some(d.as_nanos() as u64);
+        Record {
+            samples: Some(stats.samples),
+            min_ns: ns(stats.min),
+            median_ns: ns(stats.median),
+            mean_ns: ns(stats.mean),
+            stddev_ns: ns(stats.stddev),
+            p95_ns: ns(stats.p95),
+            ..self
+        }
+    }
+
+    pub fn with_verdict(self, verdict: &Verdict) -> Self {
+        Record {
+            verified: Some(verdict.label()),
+            expected: match verdict {
+                Verdict::Fail { expected } => Some(expected.clone()),
+                Verdict::Pass => self.answer.clone(),
+                Verdict::Unknown => None,
+            },
+            ..self
+        }
+    }
+}
+
+/// Writes `records` as a JSON array or as CSV with a header row. `Format::Text` is printed by the runner itself.
+pub fn write_records<W: io::Write>(
+    format: Format,
+    records: &[Record],
+    writer: W,
+) -> Result<(), Box<dyn error::Error>> {
+    match format {
+        Format::Text => Ok(()),
+        Format::Json => {
+            let mut writer = writer;
+            serde_json::to_writer_pretty(&mut writer, records)?;
+            writeln!(writer)?;
+            Ok(())
+        }
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(writer);
+            for record in records {
+                writer.serialize(record)?;
+            }
+            writer.flush()?;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record::from_result(
+                2022,
+                1,
+                &PartResult {
+                    part: 1,
+                    outcome: Ok(Answer::Int(24000)),
+                    elapsed: Duration::from_nanos(1500),
+                    panicked: false,
+                },
+            )
+            .with_verdict(&Verdict::Pass),
+            Record::from_result(
+                2022,
+                1,
+                &PartResult {
+                    part: 2,
+                    outcome: Err("Could not parse x, because \"y\"".to_string()),
+                    elapsed: Duration::from_nanos(20),
+                    panicked: false,
+                },
+            ),
+            Record::no_input(2022, 2, 1, "\"src/inputs/2022/02.txt\" is empty".to_string()),
+            Record::not_scaffolded(2022, 3, 1),
+        ]
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut out = vec![];
+        write_records(Format::Csv, &records(), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "year,day,part,status,answer,error,elapsed_ns,samples,min_ns,median_ns,mean_ns,stddev_ns,p95_ns,verified,expected\n\
+             2022,1,1,solved,24000,,1500,,,,,,,pass,24000\n\
+             2022,1,2,error,,\"Could not parse x, because \"\"y\"\"\",20,,,,,,,,\n\
+             2022,2,1,no_input,,\"\"\"src/inputs/2022/02.txt\"\" is empty\",,,,,,,,,\n\
+             2022,3,1,not_scaffolded,,,,,,,,,,,\n"
+        );
+    }
+
+    #[test]
+    fn test_write_json() {
+        let mut out = vec![];
+        write_records(Format::Json, &records(), &mut out).unwrap();
+        let parsed: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(parsed[0]["year"], 2022);
+        assert_eq!(parsed[0]["answer"], 24000);
+        assert_eq!(parsed[1]["status"], "error");
+        assert_eq!(parsed[2]["status"], "no_input");
+        assert_eq!(parsed[3]["status"], "not_scaffolded");
+        assert!(parsed[3]["elapsed_ns"].is_null());
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("csv".parse::<Format>().unwrap(), Format::Csv);
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,382 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::error;
+
+use crate::input::{self, project_dir, InputError};
+use crate::{puzzle, template};
+
+/// The registry of a year that has no days yet.
+pub const YEAR_TEMPLATE: &str = "use advent_of_code::solution::Registry;\n\ndays! {\n}\n";
+
+/// Starts `answers/<year>/NN.toml`; `cargo submit` adds accepted answers to `[input]`.
+pub const ANSWERS_TEMPLATE: &str = "[input]\n\n[example]\n";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Created,
+    /// An existing file got a missing entry, or an empty example was filled in.
+    Updated,
+    Existed,
+    /// Not something scaffolding creates, and not there yet; the note says how to get it.
+    Missing,
+    /// Scaffolding could not create or update it; the note says what to do.
+    Skipped,
+}
+
+impl Status {
+    pub fn label(&self, dry_run: bool) -> &'static str {
+        match (self, dry_run) {
+            (Status::Created, false) => "Created",
+            (Status::Created, true) => "Would create",
+            (Status::Updated, false) => "Updated",
+            (Status::Updated, true) => "Would update",
+            (Status::Existed, _) => "Existed",
+            (Status::Missing, _) => "Missing",
+            (Status::Skipped, _) => "Skipped",
+        }
+    }
+}
+
+/// One file a day needs, and what scaffolding does about it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Artifact {
+    /// Relative to the project directory.
+    pub path: String,
+    pub status: Status,
+    pub note: Option<String>,
+    contents: Option<String>,
+}
+
+impl Artifact {
+    fn new(path: String, status: Status, note: Option<String>, contents: Option<String>) -> Self {
+        Artifact {
+            path,
+            status,
+            note,
+            contents,
+        }
+    }
+
+    pub fn print(&self, dry_run: bool) {
+        match &self.note {
+            Some(note) => println!(
+                "{:<12} {} ({})",
+                self.status.label(dry_run),
+                self.path,
+                note
+            ),
+            None => println!("{:<12} {}", self.status.label(dry_run), self.path),
+        }
+    }
+}
+
+/// Adds `<ident> => "<target>",` to the `<name>! { ... }` block of `source`, keeping the entries sorted.
+/// `Ok(None)` if `ident` is already listed.
+pub fn add_entry(
+    source: &str,
+    name: &str,
+    ident: &str,
+    target: &str,
+) -> Result<Option<String>, String> {
+    let open = format!("{}! {{", name);
+    let start = source
+        .find(&open)
+        .ok_or_else(|| format!("no `{}` block", open))?
+        + open.len();
+    let end = start
+        + source[start..]
+            .find("\n}")
+            .ok_or_else(|| format!("the `{}` block is not closed", open))?
+        + 1;
+
+    let mut insert_at = end;
+    let mut offset = start;
+    for line in source[start..end].split_inclusive('\n') {
+        let existing = line.split("=>").next().unwrap_or_default().trim();
+        if existing == ident {
+            return Ok(None);
+        }
+        if !existing.is_empty() && existing > ident && insert_at == end {
+            insert_at = offset;
+        }
+        offset += line.len();
+    }
+    if insert_at == end && !source[..end].ends_with('\n') {
+        insert_at = end - 1;
+    }
+
+    let entry = format!("    {} => \"{}\",\n", ident, target);
+    Ok(Some(format!(
+        "{}{}{}",
+        &source[..insert_at],
+        entry,
+        &source[insert_at..]
+    )))
+}
+
+fn exists(path: &str) -> bool {
+    project_dir().join(path).exists()
+}
+
+fn read(path: &str) -> Result<String, Box<dyn error::Error>> {
+    Ok(input::read_path(&project_dir().join(path))?)
+}
+
+/// The artifact for the example file, given what reading it gave. Only a missing or empty file is filled in
+/// with the example from the puzzle description; one that cannot be read is left alone.
+fn example_artifact(
+    path: String,
+    existing: Result<String, InputError>,
+    example: Option<String>,
+) -> Artifact {
+    let from_puzzle = || Some("from the puzzle description".to_string());
+    match (existing, example) {
+        (Ok(_), _) | (Err(InputError::Empty(_)), None) => {
+            Artifact::new(path, Status::Existed, None, None)
+        }
+        (Err(InputError::Empty(_)), Some(example)) => {
+            Artifact::new(path, Status::Updated, from_puzzle(), Some(example))
+        }
+        (Err(InputError::Missing(_)), Some(example)) => {
+            Artifact::new(path, Status::Created, from_puzzle(), Some(example))
+        }
+        (Err(InputError::Missing(_)), None) => Artifact::new(
+            path,
+            Status::Created,
+            Some("empty".to_string()),
+            Some(String::new()),
+        ),
+        (Err(e), _) => Artifact::new(
+            path,
+            Status::Skipped,
+            Some(format!("{}, fix or remove it and scaffold again", e)),
+            None,
+        ),
+    }
+}
+
+/// The artifact for a registry file that needs `<ident> => "<target>",` in its `<name>!` block.
+fn registry(
+    path: String,
+    source: Option<String>,
+    default: &str,
+    name: &str,
+    ident: &str,
+    target: &str,
+) -> Artifact {
+    let created = source.is_none();
+    let source = source.unwrap_or_else(|| default.to_string());
+    let note = Some(format!("registers {}", ident));
+    match add_entry(&source, name, ident, target) {
+        Ok(Some(updated)) if created => Artifact::new(path, Status::Created, note, Some(updated)),
+        Ok(Some(updated)) => Artifact::new(path, Status::Updated, note, Some(updated)),
+        Ok(None) => Artifact::new(path, Status::Existed, None, None),
+        Err(e) => Artifact::new(
+            path,
+            Status::Skipped,
+            Some(format!("{}, add `{} => \"{}\",` by hand", e, ident, target)),
+            None,
+        ),
+    }
+}
+
+/// Works out every file day `day` of `year` needs: its module, its entries in the day and year registries,
+/// input, example, answers and puzzle description. Nothing is written, see `apply`.
+pub fn plan(
+    year: u16,
+    day: u8,
+    template_name: &str,
+) -> Result<Vec<Artifact>, Box<dyn error::Error>> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("there is no day {}, days go from 1 to 25", day).into());
+    }
+    let padded = format!("{:02}", day);
+    let mut artifacts = vec![];
+
+    let module_path = format!("src/solutions/{}/{}.rs", year, padded);
+    artifacts.push(if exists(&module_path) {
+        Artifact::new(module_path, Status::Existed, None, None)
+    } else {
+        let module = template::render(&template::load(template_name)?, year, day)?;
+        Artifact::new(
+            module_path,
+            Status::Created,
+            Some(format!("from template \"{}\"", template_name)),
+            Some(module),
+        )
+    });
+
+    let year_path = format!("src/solutions/{}/mod.rs", year);
+    let year_module = match exists(&year_path) {
+        true => Some(read(&year_path)?),
+        false => None,
+    };
+    artifacts.push(registry(
+        year_path,
+        year_module,
+        YEAR_TEMPLATE,
+        "days",
+        &format!("day{}", padded),
+        &format!("{}.rs", padded),
+    ));
+    artifacts.push(registry(
+        "src/main.rs".to_string(),
+        Some(read("src/main.rs")?),
+        "",
+        "years",
+        &format!("y{}", year),
+        &format!("solutions/{}/mod.rs", year),
+    ));
+
+    let input_path = format!("src/inputs/{}/{}.txt", year, padded);
+    artifacts.push(if exists(&input_path) {
+        Artifact::new(input_path, Status::Existed, None, None)
+    } else {
+        Artifact::new(
+            input_path,
+            Status::Created,
+            Some("empty".to_string()),
+            Some(String::new()),
+        )
+    });
+
+    // fills the example from a puzzle description cached by `cargo download`, if there is one.
+    let example = match puzzle::load(year, day) {
+        Ok(description) => puzzle::first_example(&description),
+        Err(e) if e.is_absent() => None,
+        Err(e) => return Err(e.into()),
+    };
+    let example_path = format!("src/examples/{}/{}.txt", year, padded);
+    let existing = input::read_path(&project_dir().join(&example_path));
+    artifacts.push(example_artifact(example_path, existing, example));
+
+    let answers_path = format!("answers/{}/{}.toml", year, padded);
+    artifacts.push(if exists(&answers_path) {
+        Artifact::new(answers_path, Status::Existed, None, None)
+    } else {
+        Artifact::new(
+            answers_path,
+            Status::Created,
+            None,
+            Some(ANSWERS_TEMPLATE.to_string()),
+        )
+    });
+
+    let puzzle_path = format!("src/puzzles/{}/{}.md", year, padded);
+    artifacts.push(if exists(&puzzle_path) {
+        Artifact::new(puzzle_path, Status::Existed, None, None)
+    } else {
+        Artifact::new(
+            puzzle_path,
+            Status::Missing,
+            Some(format!(
+                "not downloaded, run `cargo download {} --year {}`",
+                day, year
+            )),
+            None,
+        )
+    });
+    Ok(artifacts)
+}
+
+/// Writes every created or updated artifact. A failed write turns that artifact into `Skipped`.
+pub fn apply(artifacts: &mut [Artifact]) {
+    for artifact in artifacts.iter_mut() {
+        let contents = match &artifact.contents {
+            Some(contents) => contents,
+            None => continue,
+        };
+        if let Err(e) = input::write_atomic(&project_dir().join(&artifact.path), contents) {
+            artifact.status = Status::Skipped;
+            artifact.note = Some(format!("could not write: {}", e));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_entry_keeps_order() {
+        let source = "days! {\n    day01 => \"01.rs\",\n    day03 => \"03.rs\",\n}\n";
+        assert_eq!(
+            add_entry(source, "days", "day02", "02.rs").unwrap().unwrap(),
+            "days! {\n    day01 => \"01.rs\",\n    day02 => \"02.rs\",\n    day03 => \"03.rs\",\n}\n"
+        );
+        assert_eq!(
+            add_entry(source, "days", "day09", "09.rs").unwrap().unwrap(),
+            "days! {\n    day01 => \"01.rs\",\n    day03 => \"03.rs\",\n    day09 => \"09.rs\",\n}\n"
+        );
+        assert_eq!(add_entry(source, "days", "day03", "03.rs").unwrap(), None);
+        assert_eq!(
+            add_entry(YEAR_TEMPLATE, "days", "day01", "01.rs")
+                .unwrap()
+                .unwrap(),
+            "use advent_of_code::solution::Registry;\n\ndays! {\n    day01 => \"01.rs\",\n}\n"
+        );
+        assert!(add_entry("fn main() {}\n", "years", "y2023", "solutions/2023/mod.rs").is_err());
+    }
+
+    #[test]
+    fn test_example_is_only_filled_in_when_missing_or_empty() {
+        let path = std::path::PathBuf::from("src/examples/2022/08.txt");
+        let status = |existing, example: Option<&str>| {
+            example_artifact(
+                "src/examples/2022/08.txt".to_string(),
+                existing,
+                example.map(String::from),
+            )
+            .status
+        };
+        assert_eq!(status(Ok("1\n".to_string()), Some("2\n")), Status::Existed);
+        assert_eq!(
+            status(Err(InputError::Empty(path.clone())), Some("2\n")),
+            Status::Updated
+        );
+        assert_eq!(
+            status(Err(InputError::Empty(path.clone())), None),
+            Status::Existed
+        );
+        assert_eq!(
+            status(Err(InputError::Missing(path.clone())), None),
+            Status::Created
+        );
+        assert_eq!(
+            status(Err(InputError::NotUtf8(path.clone())), Some("2\n")),
+            Status::Skipped
+        );
+        let unreadable = InputError::Unreadable(path, std::io::ErrorKind::PermissionDenied.into());
+        assert_eq!(status(Err(unreadable), Some("2\n")), Status::Skipped);
+    }
+
+    #[test]
+    fn test_plan_writes_nothing() {
+        let artifacts = plan(1999, 1, template::DEFAULT_TEMPLATE).unwrap();
+        let status = |path: &str| artifacts.iter().find(|a| a.path == path).map(|a| a.status);
+
+        assert_eq!(status("src/solutions/1999/01.rs"), Some(Status::Created));
+        assert_eq!(status("src/solutions/1999/mod.rs"), Some(Status::Created));
+        assert_eq!(status("src/main.rs"), Some(Status::Updated));
+        assert_eq!(status("answers/1999/01.toml"), Some(Status::Created));
+        assert_eq!(status("src/puzzles/1999/01.md"), Some(Status::Missing));
+        assert!(!project_dir().join("src/solutions/1999").exists());
+        assert!(plan(1999, 0, template::DEFAULT_TEMPLATE).is_err());
+        assert!(plan(1999, 26, template::DEFAULT_TEMPLATE).is_err());
+
+        let existing = plan(2022, 8, template::DEFAULT_TEMPLATE).unwrap();
+        for path in [
+            "src/solutions/2022/08.rs",
+            "src/solutions/2022/mod.rs",
+            "src/main.rs",
+            "answers/2022/08.toml",
+        ] {
+            assert_eq!(
+                existing.iter().find(|a| a.path == path).map(|a| a.status),
+                Some(Status::Existed)
+            );
+        }
+    }
+}
@@ -0,0 +1,267 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::bench::{self, BenchConfig, Stats};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// A day's puzzle, implemented by every `src/solutions/<year>/NN.rs` so the runner can call it in-process.
+/// `Input` says what the parts take: `&'a str`, `&'a [u8]` or `io::BufReader<&'a [u8]>` for solutions that
+/// read line by line. The runner converts the input file to it, so every kind is timed and benchmarked alike.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    type Input<'a>: Input<'a>;
+    type PartOne: Into<Answer>;
+    type PartTwo: Into<Answer>;
+    type Error: Display;
+
+    fn part_one(input: Self::Input<'_>) -> Result<Self::PartOne, Self::Error>;
+    fn part_two(input: Self::Input<'_>) -> Result<Self::PartTwo, Self::Error>;
+
+    /// Runs part one on the input file's contents.
+    fn solve_part_one(input: &str) -> Result<Self::PartOne, Self::Error> {
+        Self::part_one(Input::from_input(input))
+    }
+
+    /// Runs part two on the input file's contents.
+    fn solve_part_two(input: &str) -> Result<Self::PartTwo, Self::Error> {
+        Self::part_two(Input::from_input(input))
+    }
+}
+
+/// Something a solution can consume, made from the contents of an input file.
+pub trait Input<'a> {
+    fn from_input(input: &'a str) -> Self;
+}
+
+impl<'a> Input<'a> for &'a str {
+    fn from_input(input: &'a str) -> Self {
+        input
+    }
+}
+
+impl<'a> Input<'a> for &'a [u8] {
+    fn from_input(input: &'a str) -> Self {
+        input.as_bytes()
+    }
+}
+
+impl<'a> Input<'a> for io::BufReader<&'a [u8]> {
+    fn from_input(input: &'a str) -> Self {
+        io::BufReader::new(input.as_bytes())
+    }
+}
+
+/// The outcome of running a single part, with the error already formatted.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub part: u8,
+    pub outcome: Result<Answer, String>,
+    pub elapsed: Duration,
+    /// The part panicked, e.g. in the `todo!()` of a freshly scaffolded day. Such parts are not benchmarked.
+    pub panicked: bool,
+}
+
+impl PartResult {
+    pub fn print(&self) {
+        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, self.part, ANSI_RESET);
+        match &self.outcome {
+            Ok(answer @ Answer::Art(art)) => {
+                println!("{}", art);
+                let letters = answer.read_letters().map(|letters| letters.to_string());
+                println!(
+                    "{} {}(elapsed: {:.2?}){}",
+                    letters.unwrap_or_else(|e| format!("unreadable: {}", e)),
+                    ANSI_ITALIC,
+                    self.elapsed,
+                    ANSI_RESET
+                );
+            }
+            Ok(result) => {
+                println!(
+                    "{} {}(elapsed: {:.2?}){}",
+                    result, ANSI_ITALIC, self.elapsed, ANSI_RESET
+                );
+            }
+            Err(e) => {
+                println!("not solved:");
+                println!("{}", e);
+            }
+        }
+    }
+}
+
+/// Times a single call of `func`; this is what the runner reports as `elapsed`.
+/// A panic is reported as an error, so one unfinished day does not stop `cargo all`.
+pub fn run_part<T: Into<Answer>, E: Display>(
+    part: u8,
+    func: impl FnOnce(&str) -> Result<T, E>,
+    input: &str,
+) -> PartResult {
+    let timer = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| func(input)));
+    let elapsed = timer.elapsed();
+    let (outcome, panicked) = match result {
+        Ok(result) => (result.map(Into::into).map_err(|e| e.to_string()), false),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            (Err(format!("panicked: {}", message)), true)
+        }
+    };
+    PartResult {
+        part,
+        outcome,
+        elapsed,
+        panicked,
+    }
+}
+
+type PartRunner = fn(&str) -> PartResult;
+type PartBench = fn(&str, &BenchConfig) -> Stats;
+
+/// A registered solution with its answer and error types erased.
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    parts: [PartRunner; 2],
+    benches: [PartBench; 2],
+}
+
+impl Entry {
+    pub fn run(&self, input: &str) -> [PartResult; 2] {
+        self.parts.map(|part| part(input))
+    }
+
+    pub fn bench(&self, input: &str, config: &BenchConfig) -> [Stats; 2] {
+        self.benches.map(|bench| bench(input, config))
+    }
+}
+
+/// Every solution known to the runner, keyed by year and day.
+#[derive(Default)]
+pub struct Registry {
+    entries: BTreeMap<(u16, u8), Entry>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: Solution>(&mut self) -> &mut Self {
+        self.entries.insert(
+            (S::YEAR, S::DAY),
+            Entry {
+                year: S::YEAR,
+                day: S::DAY,
+                parts: [
+                    |input| run_part(1, S::solve_part_one, input),
+                    |input| run_part(2, S::solve_part_two, input),
+                ],
+                benches: [
+                    |input, config| bench::bench(S::solve_part_one, input, config),
+                    |input, config| bench::bench(S::solve_part_two, input, config),
+                ],
+            },
+        );
+        self
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&Entry> {
+        self.entries.get(&(year, day))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Fake;
+
+    impl Solution for Fake {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 3;
+        type Input<'a> = &'a str;
+        type PartOne = u32;
+        type PartTwo = String;
+        type Error = String;
+
+        fn part_one(input: &str) -> Result<u32, String> {
+            Ok(input.len() as u32)
+        }
+
+        fn part_two(_input: &str) -> Result<String, String> {
+            Err("unsolvable".to_string())
+        }
+    }
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 4;
+        type Input<'a> = io::BufReader<&'a [u8]>;
+        type PartOne = usize;
+        type PartTwo = usize;
+        type Error = io::Error;
+
+        fn part_one(input: io::BufReader<&[u8]>) -> Result<usize, io::Error> {
+            Ok(io::BufRead::lines(input).count())
+        }
+
+        fn part_two(input: io::BufReader<&[u8]>) -> Result<usize, io::Error> {
+            io::BufRead::lines(input).try_fold(0, |longest, line| Ok(longest.max(line?.len())))
+        }
+    }
+
+    #[test]
+    fn test_registry_runs_both_parts() {
+        let mut registry = Registry::new();
+        registry.register::<Fake>();
+
+        assert!(registry.get(2022, 1).is_none());
+        assert!(registry.get(2021, 3).is_none());
+        let [one, two] = registry.get(2022, 3).unwrap().run("abcd");
+        assert_eq!(one.outcome, Ok(Answer::Int(4)));
+        assert_eq!(two.outcome, Err("unsolvable".to_string()));
+        assert!(!two.panicked);
+
+        let todo = run_part(1, |_| -> Result<u32, String> { todo!() }, "abcd");
+        assert_eq!(todo.outcome, Err("panicked: not yet implemented".to_string()));
+        assert!(todo.panicked);
+
+        let config = BenchConfig {
+            samples: 3,
+            ..BenchConfig::default()
+        };
+        let [one, two] = registry.get(2022, 3).unwrap().bench("abcd", &config);
+        assert_eq!((one.samples, two.samples), (3, 3));
+    }
+
+    #[test]
+    fn test_registry_runs_bufread_solutions() {
+        let mut registry = Registry::new();
+        registry.register::<Lines>();
+
+        let [one, two] = registry.get(2022, 4).unwrap().run("ab\nabcd\nc\n");
+        assert_eq!(one.outcome, Ok(Answer::Int(3)));
+        assert_eq!(two.outcome, Ok(Answer::Int(4)));
+        assert_eq!(<&[u8]>::from_input("ab"), b"ab");
+    }
+}
@@ -1,8 +1,8 @@
 use advent_of_code::helpers::err::TokenError;
+use advent_of_code::Solution;
 use itertools::Itertools;
-use std::error::Error;
 
-pub fn part_one(input: &str) -> Result<u32, impl Error> {
+pub fn part_one(input: &str) -> Result<u32, TokenError> {
     // Does the spec guarentee that blank lines are blank and not just whitespace?
     input
         .split("\n\n")
@@ -11,10 +11,7 @@ pub fn part_one(input: &str) -> Result<u32, impl Error> {
                 .split("\n")
                 .filter(|elf| !elf.is_empty())
                 .map(|elf_cal| match elf_cal.parse::<u32>() {
-                    Err(e) => Err(TokenError {
-                        token: elf_cal.to_string(),
-                        reason: e.to_string(),
-                    }),
+                    Err(e) => Err(TokenError::at(input, elf_cal, e.to_string())),
                     Ok(cal) => Ok(cal),
                 })
                 .fold_ok(u32::MIN, std::ops::Add::add)
@@ -22,24 +19,18 @@ pub fn part_one(input: &str) -> Result<u32, impl Error> {
         .fold_ok(u32::MIN, std::cmp::max)
 }
 
-pub fn part_two(input: &str) -> Result<u32, impl Error> {
+pub fn part_two(input: &str) -> Result<u32, TokenError> {
     let calories_by_elf = input.split("\n\n").map(|elf_input: &str| {
         elf_input
             .split("\n")
             .filter(|elf| !elf.is_empty())
             .map(|elf_cal| match elf_cal.parse::<u32>() {
-                Err(e) => Err(TokenError {
-                    token: elf_cal.to_string(),
-                    reason: e.to_string(),
-                }),
+                Err(e) => Err(TokenError::at(input, elf_cal, e.to_string())),
                 Ok(cal) => Ok(cal),
             })
             .fold_ok(0u32, std::ops::Add::add)
     });
-    match calories_by_elf.clone().find(|a| match a {
-        Err(_) => true,
-        Ok(_) => false,
-    }){
+    match calories_by_elf.clone().find(|a| a.is_err()){
         Some(e) => Err(e.expect_err("calories_by_elf find should match Err")),
         None => Ok(calories_by_elf
                 .map(|a| a.expect("All calories should be good"))
@@ -47,28 +38,36 @@ pub fn part_two(input: &str) -> Result<u32, impl Error> {
                 .take(3).sum()),
     }
 }
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+
+pub struct Day;
+
+impl Solution for Day {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+    type Error = TokenError;
+
+    fn part_one(input: &str) -> Result<u32, TokenError> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<u32, TokenError> {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    macro_rules! get_input {
-        () => {
-            &advent_of_code::read_file("inputs", 1)
-        };
-    }
-    #[test]
-    fn test_part_one() {
-        part_one(get_input!()).expect("First set of inputs resolves to a value.");
-    }
+    advent_of_code::solution_tests!(Day);
 
     #[test]
-    fn test_part_two() {
-        part_two(get_input!()).expect("Second set of inputs resolves to a value.");
+    fn test_bad_calories_are_located() {
+        let e = part_two("1000\n2000\n\n3x00\n").unwrap_err();
+        let span = e.span.unwrap();
+        assert_eq!((e.token.as_str(), span.line, span.column), ("3x00", 4, 1));
     }
 }
@@ -0,0 +1,143 @@
+use advent_of_code::helpers::err::TokenError;
+use advent_of_code::helpers::parse;
+use advent_of_code::Solution;
+use itertools::Itertools;
+
+// We get a tiiiiiny bit of extra performance in L1/L2(?) caches if we reduce the size of these; no point going below a byte because we can't address closer than that though (and the stack needs to remain 16-aligned).
+#[repr(u8)]
+#[derive(Clone, Copy)]
+enum Rps {
+    Rock = 0,
+    Paper,
+    Scissors,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+enum Outcome {
+    // We could get a touch more performance if we allowed lose to be 3, but this way it's much easier to calculate
+    Lose = 0,
+    Tie,
+    Win,
+}
+
+#[inline]
+fn try_from_opp(input: &str, letter: &str) -> Result<Rps, TokenError> {
+    match letter {
+        "A" => Ok(Rps::Rock),
+        "B" => Ok(Rps::Paper),
+        "C" => Ok(Rps::Scissors),
+        _ => Err(TokenError::at(input, letter, "Unexpected token, expecting A B or C")),
+    }
+}
+
+#[inline]
+fn try_from_out(input: &str, letter: &str) -> Result<Outcome, TokenError> {
+    match letter {
+        "X" => Ok(Outcome::Lose),
+        "Y" => Ok(Outcome::Tie),
+        "Z" => Ok(Outcome::Win),
+        _ => Err(TokenError::at(input, letter, "Unexpected token, expecting X Y or Z")),
+    }
+}
+
+#[inline]
+fn try_from_you(input: &str, letter: &str) -> Result<Rps, TokenError> {
+    match letter {
+        "X" => Ok(Rps::Rock),
+        "Y" => Ok(Rps::Paper),
+        "Z" => Ok(Rps::Scissors),
+        _ => Err(TokenError::at(input, letter, "Unexpected token, expecting X Y or Z")),
+    }
+}
+
+/*
+This is kinda dumb but it works and is probably super fast. There's something here about finite groups operations but meh
+
+R R T 0 0 1
+R P W 0 1 2
+R S L 0 2 0
+
+P R L 1 0 0
+P P T 1 1 1
+P S W 1 2 2
+
+S R W 2 0 2
+S P L 2 1 0
+S S T 2 2 0
+
+2 * opp + you + 1 % 3
+*/
+
+#[inline(always)]
+fn rps(opp: Rps, you: Rps) -> Outcome {
+    // Evil hack. Be very careful here. speedy_transmute look safe but is actually unsafe so yannow there's that.
+    // We should never end up with a value that isn't a valid RPS value thanks to the %3u8 so don't touch that.
+    unsafe { std::mem::transmute((opp as u8 + opp as u8 + you as u8 + 1u8) % 3u8) }
+}
+
+#[inline(always)]
+fn solve_rps(opp: Rps, out: Outcome) -> Rps {
+    // Again, another evil hack. Be very careful.
+    // This is the inverse of RPS, which should be easily derived; we add 8 instead of subtracting 1 because we're in base 3 and we need to make sure that the unsigned int doesn't go negative.
+    
+    unsafe { std::mem::transmute((out as u8 + 8u8 - opp as u8 - opp as u8) % 3u8) }
+}
+
+pub fn part_one(input: &str) -> Result<u32, TokenError> {
+    parse::lines(input)
+        .map(|line| -> Result<u32, TokenError> {
+            let round: [&str; 2] = parse::split_n(input, line, " ")?;
+
+            let opp = try_from_opp(input, round[0])?;
+            let you = try_from_you(input, round[1])?;
+            Ok(1 + you as u32 + 3 * rps(opp, you) as u32)
+        })
+        .fold_ok(u32::MIN, std::ops::Add::add)
+}
+
+pub fn part_two(input: &str) -> Result<u32, TokenError> {
+    parse::lines(input)
+        .map(|line| -> Result<u32, TokenError> {
+            let round: [&str; 2] = parse::split_n(input, line, " ")?;
+
+            let opp = try_from_opp(input, round[0])?;
+            let out = try_from_out(input, round[1])?;
+            Ok(1 + solve_rps(opp, out) as u32 + 3 * out as u32)
+        })
+        .fold_ok(u32::MIN, std::ops::Add::add)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+    type Error = TokenError;
+
+    fn part_one(input: &str) -> Result<u32, TokenError> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<u32, TokenError> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::solution_tests!(Day);
+
+    #[test]
+    fn test_bad_letter_is_located() {
+        let span = part_two("A Y\nB Q\n").unwrap_err().span.unwrap();
+        assert_eq!((span.line, span.column, span.len), (2, 3, 1));
+        let span = part_one("A Y\nD X\n").unwrap_err().span.unwrap();
+        assert_eq!((span.line, span.column, span.len), (2, 1, 1));
+    }
+}
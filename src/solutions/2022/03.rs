@@ -0,0 +1,137 @@
+use advent_of_code::helpers::err::TokenError;
+use advent_of_code::Solution;
+use itertools::Itertools;
+use seq_macro::seq;
+
+
+macro_rules! check_ASCII_bounds {
+    ($input:ident, $line:expr, $i:ident, $byte:ident) => {
+        if !(b'A'..=b'z').contains($byte) {
+            // every byte before this one is ASCII, so a character starts here.
+            let len = $line[$i..].chars().next().map_or(1, char::len_utf8);
+            return Err(TokenError::at(
+                $input,
+                &$line[$i..$i + len],
+                format!("Expected a value between {} (ascii/utf-8 A) and {} (ascii/utf-8 z)", b'A', b'z'),
+            ));
+        }
+    };
+}
+
+macro_rules! get_priority_from_ascii {
+    ($input:ident, $line:expr, $i:ident, $byte:ident) => {
+        match $byte {
+            b'A'..=b'Z' => Ok(($byte - &b'A' + 27) as u32),
+            b'a'..=b'z' => Ok(($byte - &b'a' + 1) as u32),
+            _ => Err(TokenError::at(
+                $input,
+                &$line[$i..$i + 1],
+                format!("Expected a value not within {} (Z) and {} (a) (implies non-alphabetic character)", b'Z', b'a'),
+            ))
+        }
+    };
+}
+
+#[inline(always)]
+fn get_ascii_shift(&byte: &u8) -> u64 {
+    1u64 << (byte-b'A')
+}
+
+#[inline(always)]
+fn set_ascii_bitfield(field:&u64, byte:&u8) -> u64 {
+    field | get_ascii_shift(byte)
+}
+
+#[inline(always)]
+fn get_ascii_bitfield(field:&u64, byte:&u8) -> bool {
+    (field & get_ascii_shift(byte)) != 0
+}
+
+pub fn part_one(input: &str) -> Result<u32, TokenError> {
+    input.split("\n").map(|line| {
+        if line.is_empty() {
+            return Ok(0);
+        }
+        let mut hash_field = 0u64;
+        let line_bytes = line.as_bytes();
+        for (i, byte) in line_bytes.iter().enumerate(){
+            check_ASCII_bounds!(input, line, i, byte);
+
+            if i < line_bytes.len()/2 {
+                hash_field = set_ascii_bitfield(&hash_field, byte);
+            }else{
+                if get_ascii_bitfield(&hash_field, byte){
+                    return get_priority_from_ascii!(input, line, i, byte);
+                }
+            }
+        }
+        Err(TokenError::at(input, line, "Expected at least one duplicate across halves"))
+    }).fold_ok(0, std::ops::Add::add)
+}
+
+pub fn part_two(input: &str) -> Result<u32, TokenError> {
+    input.split("\n").chunks(3).into_iter().map(|chunk| {
+        let elfs = chunk.collect_vec();
+        if elfs.len() == 1 && elfs[0].is_empty() {
+            return Ok(0);
+        }
+        assert_eq!(elfs.len(), 3, "Should have a chunk of size 3");
+        seq!(n in 0..2 {
+            let mut bit_field_~n = 0u64;
+            for (i, byte) in elfs[n].as_bytes().iter().enumerate() {
+                check_ASCII_bounds!(input, elfs[n], i, byte);
+                bit_field_~n = set_ascii_bitfield(&bit_field_~n, byte);
+            }
+        });
+        for (i, byte) in elfs[2].as_bytes().iter().enumerate() {
+            check_ASCII_bounds!(input, elfs[2], i, byte);
+            if bit_field_0 & bit_field_1 & get_ascii_shift(byte) != 0 {
+                return get_priority_from_ascii!(input, elfs[2], i, byte);
+            }
+        }
+        Err(TokenError::at(input, elfs[0], "Expected at least one commonality across the group starting here"))
+        
+    }).fold_ok(0, std::ops::Add::add)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+    type Error = TokenError;
+
+    fn part_one(input: &str) -> Result<u32, TokenError> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<u32, TokenError> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::solution_tests!(Day);
+
+    #[test]
+    fn test_errors_are_located() {
+        let located = |result: Result<u32, TokenError>| {
+            let e = result.unwrap_err();
+            let span = e.span.unwrap();
+            (e.token, span.line, span.column)
+        };
+        assert_eq!(located(part_one("abca\nab1cab\n")), ("1".to_string(), 2, 3));
+        assert_eq!(located(part_one("abca\nabécab\n")), ("é".to_string(), 2, 3));
+        assert_eq!(located(part_one("abca\nab[c[b\n")), ("[".to_string(), 2, 5));
+        assert_eq!(located(part_one("abca\nabcd\n")), ("abcd".to_string(), 2, 1));
+        assert_eq!(located(part_two("ab\nac\nad\nab\nab\n~a\n")), ("~".to_string(), 6, 1));
+        assert_eq!(located(part_two("ab\ncd\nef\nab\nab\nab\n")), ("ab".to_string(), 1, 1));
+    }
+}
+
@@ -0,0 +1,67 @@
+use advent_of_code::helpers::err::TokenError;
+use advent_of_code::helpers::parse;
+use advent_of_code::Solution;
+use itertools::Itertools;
+
+/// The sections of both elves on a line of the form `2-4,6-8`.
+fn ranges(input: &str, line: &str) -> Result<(u32, u32, u32, u32), TokenError> {
+    let (elf_1_start, elf_1_end, elf_2_start, elf_2_end) = advent_of_code::parse!(input, line, "{}-{},{}-{}" => u32, u32, u32, u32)?;
+    if elf_1_start > elf_1_end {
+        return Err(TokenError::at(input, line, format!("start ({elf_1_start}) is after end ({elf_1_end})")));
+    }
+    if elf_2_start > elf_2_end {
+        return Err(TokenError::at(input, line, format!("start ({elf_2_start}) is after end ({elf_2_end})")));
+    }
+    Ok((elf_1_start, elf_1_end, elf_2_start, elf_2_end))
+}
+
+pub fn part_one(input: &str) -> Result<u32, TokenError> {
+    parse::lines(input)
+        .map(|line| {
+            let (elf_1_start, elf_1_end, elf_2_start, elf_2_end) = ranges(input, line)?;
+            Ok(((elf_1_start <= elf_2_start)
+            && (elf_1_end >= elf_2_end)
+            || (elf_1_start >= elf_2_start) && (elf_1_end <= elf_2_end))
+            as u32)
+        })
+        .fold_ok(0, std::ops::Add::add)
+}
+
+pub fn part_two(input: &str) -> Result<u32, TokenError> {
+    parse::lines(input)
+        .map(|line| {
+            let (elf_1_start, elf_1_end, elf_2_start, elf_2_end) = ranges(input, line)?;
+            if elf_1_start <= elf_2_start {
+                Ok((elf_1_end >= elf_2_start) as u32)
+            } else {
+                Ok((elf_1_start <= elf_2_end) as u32)
+            }
+        })
+        .fold_ok(0, std::ops::Add::add)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+    type Error = TokenError;
+
+    fn part_one(input: &str) -> Result<u32, TokenError> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<u32, TokenError> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::solution_tests!(Day);
+}
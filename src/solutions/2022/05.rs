@@ -0,0 +1,291 @@
+use advent_of_code::helpers::err::TokenError;
+use advent_of_code::Solution;
+use enum_derive::{enum_derive_util, EnumFromInner};
+use itertools::Itertools;
+use std::error;
+use std::io;
+use std::io::Read;
+use unicode_segmentation::UnicodeSegmentation;
+use custom_derive::custom_derive;
+
+#[derive(Debug, Clone)]
+pub struct EmptyStackError;
+#[derive(Debug, Clone)]
+
+pub struct InvalidStackError {
+    stack: usize,
+    max: usize,
+}
+#[derive(Debug, Clone)]
+pub struct MoveTooDeepError{
+    stack_height: u32,
+    stack: String,
+}
+
+
+impl std::fmt::Display for EmptyStackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("Stack is entirely empty!")
+    }
+}
+impl std::fmt::Display for InvalidStackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_fmt(format_args!("Stack {} out of range (max: {})!", self.stack, self.max))
+    }
+}
+impl std::fmt::Display for MoveTooDeepError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_fmt(format_args!("Can't move {} containers; not enough! Stack is {}", self.stack_height, self.stack))
+    }
+}
+
+custom_derive! {
+    #[derive(EnumFromInner, Debug)]
+    pub enum Error {
+        EmptyStackError(EmptyStackError),
+        Error(std::io::Error),
+        TokenError(TokenError),
+        InvalidStackError(InvalidStackError),
+        MoveTooDeepError(MoveTooDeepError)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::EmptyStackError(e) => e.fmt(f),
+            Error::Error(e) => e.fmt(f),
+            Error::TokenError(e) => e.fmt(f),
+            Error::InvalidStackError(e) => e.fmt(f),
+            Error::MoveTooDeepError(e) => e.fmt(f),
+        }
+    }
+}
+
+impl error::Error for EmptyStackError {
+
+}
+
+
+fn get_stacks_from_text(input: &str, drawing: Vec<&str>) -> Result<Vec<Vec<String>>, Error> {
+    let mut stacks: Vec<Vec<String>>;
+    let mut lines = drawing.into_iter().rev();
+    match lines.next() {
+        Some(line) => {
+            let line_parts = line.split(" ");
+            stacks = Vec::with_capacity(line_parts.size_hint().0);
+            for (i, token) in line_parts.enumerate() {
+                if i % 3 == 1 {
+                    if token != (1 + (i / 3)).to_string() {
+                        return Err(Error::TokenError(TokenError::at(
+                            input,
+                            token,
+                            format!(
+                                "Error parsing {line}, expecting {} but got {token}",
+                                (i / 3) + 1
+                            ),
+                        )));
+                    }
+                    stacks.push(vec![]);
+                } else {
+                    if !token.is_empty() {
+                        return Err(Error::TokenError(TokenError::at(
+                            input,
+                            token,
+                            format!(
+                                "Error parsing {line}, expecting empty space but got {token}"
+                            ),
+                        )));
+                    }
+                }
+            }
+        }
+        None => {
+            return Err(Error::TokenError(TokenError::new(
+                "",
+                "expected stack layout, but file looks empty!",
+            )));
+        }
+    }
+    for line in lines {
+        for (i, c) in UnicodeSegmentation::graphemes(line, true).enumerate() {
+            if (i / 4) >= stacks.len() {
+                return Err(Error::TokenError(TokenError::at(
+                    input,
+                    line,
+                    format!(
+                        "container at {i} is outside the ship with length {}",
+                        stacks.len()
+                    ),
+                )));
+            }
+            match i % 4 {
+                0 => {
+                    if c != "[" && c != " " {
+                        return Err(Error::TokenError(TokenError::at(
+                            input,
+                            c,
+                            format!("Error reading {line}, expecting ["),
+                        )));
+                    }
+                }
+                1 => {
+                    if c != " " {
+                        stacks[i/4].push(c.to_string());
+                    }
+                }
+                2 => {
+                    if c != "]" && c != " " {
+                        return Err(Error::TokenError(TokenError::at(
+                            input,
+                            c,
+                            format!("Error reading {line}, expecting ["),
+                        )));
+                    }
+                }
+                3 => {
+                    if c != " " {
+                        return Err(Error::TokenError(TokenError::at(
+                            input,
+                            c,
+                            format!("Error reading {line}, expecting space"),
+                        )));
+                    }
+                }
+                _ => panic!("hey wtf")
+            }
+        }
+    }
+    Ok(stacks)
+}
+
+/// Moves the crates as listed below the drawing. A `one_at_a_time` crane reverses every moved pile.
+fn rearrange<T: std::io::Read>(
+    mut input: io::BufReader<T>,
+    one_at_a_time: bool,
+) -> Result<String, Error> {
+    let mut text = String::new();
+    input.read_to_string(&mut text)?;
+    let input = text.as_str();
+
+    let mut lines = input.lines();
+    let drawing: Vec<&str> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+    let mut stacks = get_stacks_from_text(input, drawing)?;
+
+    for line in lines {
+        let (stack_height, from_stack, to_stack) =
+            advent_of_code::parse!(input, line, "move {} from {} to {}" => u32, usize, usize)?;
+        for stack in [from_stack, to_stack] {
+            if !(1..=stacks.len()).contains(&stack) {
+                return Err(Error::InvalidStackError(InvalidStackError {
+                    stack,
+                    max: stacks.len(),
+                }));
+            }
+        }
+        let from = &mut stacks[from_stack - 1];
+        let split_index = from
+            .len()
+            .checked_sub(stack_height as usize)
+            .ok_or_else(|| MoveTooDeepError {
+                stack_height,
+                stack: from.join(""),
+            })?;
+        let mut moved = from.split_off(split_index);
+        if one_at_a_time {
+            moved.reverse();
+        }
+        stacks[to_stack - 1].extend(moved);
+    }
+
+    let tops: Vec<&String> = stacks
+        .iter()
+        .map(|stack| stack.last().ok_or(EmptyStackError))
+        .collect::<Result<_, _>>()?;
+    Ok(tops.into_iter().join(""))
+}
+
+pub fn part_one<T: std::io::Read>(input: io::BufReader<T>) -> Result<String, Error> {
+    rearrange(input, true)
+}
+
+pub fn part_two<T: std::io::Read>(input: io::BufReader<T>) -> Result<String, Error> {
+    rearrange(input, false)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+    type Input<'a> = io::BufReader<&'a [u8]>;
+    type PartOne = String;
+    type PartTwo = String;
+    type Error = Error;
+
+    fn part_one(input: io::BufReader<&[u8]>) -> Result<String, Error> {
+        part_one(input)
+    }
+
+    fn part_two(input: io::BufReader<&[u8]>) -> Result<String, Error> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::solution_tests!(Day);
+
+    fn run(one_at_a_time: bool, input: &str) -> Result<String, Error> {
+        rearrange(io::BufReader::new(input.as_bytes()), one_at_a_time)
+    }
+
+    #[test]
+    fn test_bad_move_is_located() {
+        let input = "[A]\n 1\n\nmove 1 from 1 to 1\nmove x from 1 to 1\n";
+        match run(true, input) {
+            Err(Error::TokenError(e)) => {
+                let span = e.span.unwrap();
+                assert_eq!((e.token.as_str(), span.line, span.column), ("x", 5, 6));
+            }
+            other => panic!("expected a token error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_impossible_moves_are_errors() {
+        let drawing = "[A]    \n[B] [C]\n 1   2 \n\n";
+        for (moves, expected) in [
+            ("move 3 from 1 to 2\n", "MoveTooDeepError"),
+            ("move 1 from 0 to 2\n", "InvalidStackError"),
+            ("move 1 from 1 to 3\n", "InvalidStackError"),
+            ("move 1 from 2 to 1\n", "EmptyStackError"),
+        ] {
+            for one_at_a_time in [true, false] {
+                let error = run(one_at_a_time, &format!("{}{}", drawing, moves)).unwrap_err();
+                let debug = format!("{:?}", error);
+                assert!(debug.starts_with(expected), "{}: {}", moves, debug);
+            }
+        }
+        assert_eq!(
+            run(true, &format!("{}move 3 from 1 to 2\n", drawing))
+                .unwrap_err()
+                .to_string(),
+            "Can't move 3 containers; not enough! Stack is BA"
+        );
+    }
+
+    #[test]
+    fn test_bad_drawing_is_located() {
+        let input = "    [D]\n[N] {C}\n 1   2\n\nmove 1 from 2 to 1\n";
+        match part_one(io::BufReader::new(input.as_bytes())) {
+            Err(Error::TokenError(e)) => {
+                let span = e.span.unwrap();
+                assert_eq!((e.token.as_str(), span.line, span.column), ("{", 2, 5));
+            }
+            other => panic!("expected a token error, got {:?}", other),
+        }
+    }
+}
@@ -1,10 +1,8 @@
-#![feature(iter_next_chunk)]
+use advent_of_code::helpers::err::TokenError;
+use advent_of_code::Solution;
+use std::collections::VecDeque;
 
-use advent_of_code::helpers::err::{self, TokenError};
-use std::{error, collections::VecDeque};
-use itertools::Itertools;
-
-pub fn part_one(input: &str) -> Result<u32, impl error::Error> {
+pub fn part_one(input: &str) -> Result<u32, TokenError> {
     const SEARCH_SIZE: usize = 3;
     let mut iter = unicode_segmentation::UnicodeSegmentation::graphemes(input, true);
     match iter.next_chunk::<SEARCH_SIZE>() {
@@ -12,14 +10,11 @@ pub fn part_one(input: &str) -> Result<u32, impl error::Error> {
             let mut last_seen = VecDeque::from(first);
             for (i, c) in iter.enumerate() {
                 if last_seen.contains(&c) {
-                    println!("{last_seen:?} contains {c}");
                     last_seen.pop_front();
                     last_seen.push_back(c);
                 } else {
-                    println!("{last_seen:?} does not contain {c}");
                     for n in 0..SEARCH_SIZE * SEARCH_SIZE {
                         if (n%SEARCH_SIZE != n/SEARCH_SIZE) && (last_seen[n%SEARCH_SIZE] == last_seen[n/SEARCH_SIZE]) {
-                            println!("but does contain a dupe");
                             last_seen.pop_front();
                             last_seen.push_back(c);
                             break;
@@ -30,20 +25,18 @@ pub fn part_one(input: &str) -> Result<u32, impl error::Error> {
                     }
                 }
             }
-            return Err(TokenError{
-                token:input.to_string(),
-                reason: format!("no block of {SEARCH_SIZE} without repeats")
-            });
+            let end = input.trim_end().len();
+            Err(TokenError::at(input, &input[end..], format!("reached the end without a block of {SEARCH_SIZE} without repeats")))
         },
-        Err(e) => {
-            return Err(TokenError { token: input.to_string(), reason: "expected any characters at all".to_string() })
+        Err(_) => {
+            Err(TokenError::at(input, input, format!("expected at least {SEARCH_SIZE} characters")))
         }
         
     }
     
 }
 
-pub fn part_two(input: &str) -> Result<u32, impl error::Error> {
+pub fn part_two(input: &str) -> Result<u32, TokenError> {
     const SEARCH_SIZE: usize = 13;
     let mut iter = unicode_segmentation::UnicodeSegmentation::graphemes(input, true);
     match iter.next_chunk::<SEARCH_SIZE>() {
@@ -51,14 +44,11 @@ pub fn part_two(input: &str) -> Result<u32, impl error::Error> {
             let mut last_seen = VecDeque::from(first);
             for (i, c) in iter.enumerate() {
                 if last_seen.contains(&c) {
-                    println!("{last_seen:?} contains {c}");
                     last_seen.pop_front();
                     last_seen.push_back(c);
                 } else {
-                    println!("{last_seen:?} does not contain {c}");
                     for n in 0..SEARCH_SIZE * SEARCH_SIZE {
                         if (n%SEARCH_SIZE != n/SEARCH_SIZE) && (last_seen[n%SEARCH_SIZE] == last_seen[n/SEARCH_SIZE]) {
-                            println!("but does contain a dupe");
                             last_seen.pop_front();
                             last_seen.push_back(c);
                             break;
@@ -69,40 +59,39 @@ pub fn part_two(input: &str) -> Result<u32, impl error::Error> {
                     }
                 }
             }
-            return Err(TokenError{
-                token:input.to_string(),
-                reason: format!("no block of {SEARCH_SIZE} without repeats")
-            });
+            let end = input.trim_end().len();
+            Err(TokenError::at(input, &input[end..], format!("reached the end without a block of {SEARCH_SIZE} without repeats")))
         },
-        Err(e) => {
-            return Err(TokenError { token: input.to_string(), reason: "expected any characters at all".to_string() })
+        Err(_) => {
+            Err(TokenError::at(input, input, format!("expected at least {SEARCH_SIZE} characters")))
         }
         
     }
 }
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 6);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+
+pub struct Day;
+
+impl Solution for Day {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+    type Error = TokenError;
+
+    fn part_one(input: &str) -> Result<u32, TokenError> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<u32, TokenError> {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    macro_rules! get_input {
-        () => {
-            &advent_of_code::read_file("inputs", 6)
-        };
-    }
-    #[test]
-    fn test_part_one() {
-        part_one(get_input!()).expect("First set of inputs resolves to a value.");
-    }
-
-    #[test]
-    fn test_part_two() {
-        part_two(get_input!()).expect("Second set of inputs resolves to a value.");
-    }
+    advent_of_code::solution_tests!(Day);
 }
 
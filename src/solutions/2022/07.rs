@@ -1,8 +1,9 @@
-use advent_of_code::helpers::err::{self, TokenError};
+use advent_of_code::helpers::err::TokenError;
+use advent_of_code::Solution;
 use itertools::Itertools;
-use std::{error, collections::{HashMap, HashSet}, borrow::Borrow};
+use std::collections::{HashMap, HashSet};
 
-pub fn part_one(input: &str) -> Result<u128, impl error::Error> {
+pub fn part_one(input: &str) -> Result<u128, TokenError> {
     let mut cwd: Vec<&str> = vec![];
     let mut folders: HashMap<String, u128> = HashMap::new();
     let mut folders_seen: HashSet<String> = HashSet::new();
@@ -18,8 +19,8 @@ pub fn part_one(input: &str) -> Result<u128, impl error::Error> {
                     "cd" => {
                         match parts[1] {
                             ".." => {
-                                if cwd.len() == 0 {
-                                    return Err(TokenError { token: user_input.to_string(), reason: "can't return from root".to_string() })
+                                if cwd.is_empty() {
+                                    return Err(TokenError::at(input, user_input, "can't return from root"))
                                 }else {
                                     cwd.pop();
                                 }
@@ -48,35 +49,27 @@ pub fn part_one(input: &str) -> Result<u128, impl error::Error> {
                                             *folders.entry(full_folder.clone()).or_insert(0) += size;
                                         }
                                     }
-                                    Err(e) => return Err(TokenError{
-                                        token: line_parts[0].to_string(),
-                                        reason: format!("{:?}",e.kind())
-                                    })
+                                    Err(e) => return Err(TokenError::at(input, line_parts[0], format!("{:?}",e.kind())))
                                 }
                             }
                         }
                     },
                     other => {
-                        return Err(TokenError {
-                            token: other.to_string(), reason: "Not a valid command!".to_string()})
+                        return Err(TokenError::at(input, other, "Not a valid command!"))
                     }
                 }
             }
-            None => {return Err(TokenError{
-                token: command.to_string(),
-                reason: "missing user input".to_string(),
-            })}
+            None => {return Err(TokenError::at(input, command, "missing user input"))}
         }
     }
 
-    return Ok(folders.values()
+    Ok(folders.values()
         .filter(|v| **v <= 100000u128)
-        .map(|v| *v)
-        .fold(0u128, std::ops::Add::add));
+        .sum::<u128>())
     
 }
 
-pub fn part_two(input: &str) -> Result<u128, impl error::Error> {
+pub fn part_two(input: &str) -> Result<u128, TokenError> {
     let mut cwd: Vec<&str> = vec![];
     let mut folders: HashMap<String, u128> = HashMap::new();
     let mut folders_seen: HashSet<String> = HashSet::new();
@@ -92,8 +85,8 @@ pub fn part_two(input: &str) -> Result<u128, impl error::Error> {
                     "cd" => {
                         match parts[1] {
                             ".." => {
-                                if cwd.len() == 0 {
-                                    return Err(TokenError { token: user_input.to_string(), reason: "can't return from root".to_string() })
+                                if cwd.is_empty() {
+                                    return Err(TokenError::at(input, user_input, "can't return from root"))
                                 }else {
                                     cwd.pop();
                                 }
@@ -123,61 +116,55 @@ pub fn part_two(input: &str) -> Result<u128, impl error::Error> {
                                             *folders.entry(full_folder.clone()).or_insert(0) += size;
                                         }
                                     }
-                                    Err(e) => return Err(TokenError{
-                                        token: line_parts[0].to_string(),
-                                        reason: format!("{:?}",e.kind())
-                                    })
+                                    Err(e) => return Err(TokenError::at(input, line_parts[0], format!("{:?}",e.kind())))
                                 }
                             }
                         }
                     },
                     other => {
-                        return Err(TokenError {
-                            token: other.to_string(), reason: "Not a valid command!".to_string()})
+                        return Err(TokenError::at(input, other, "Not a valid command!"))
                     }
                 }
             }
-            None => {return Err(TokenError{
-                token: command.to_string(),
-                reason: "missing user input".to_string(),
-            })}
+            None => {return Err(TokenError::at(input, command, "missing user input"))}
         }
     }
 
     let current_free = 70000000 - folders["/"];
 
     let mut potential_folders = folders.values()
-        .filter(|v| (**v + current_free >= 30000000u128)).sorted();
+        .filter(|v| **v + current_free >= 30000000u128).sorted();
 
     match potential_folders.next() {
-        Some(v) => return Ok(*v),
-        None => return Err(TokenError { token: input.to_string(), reason: "no folder is large enough for delete".to_string() })
+        Some(v) => Ok(*v),
+        None => Err(TokenError::new("/", format!("no folder is large enough for delete, {} more bytes are needed", 30000000u128.saturating_sub(current_free))))
     }
     
 }
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+
+pub struct Day;
+
+impl Solution for Day {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+    type Input<'a> = &'a str;
+    type PartOne = u128;
+    type PartTwo = u128;
+    type Error = TokenError;
+
+    fn part_one(input: &str) -> Result<u128, TokenError> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<u128, TokenError> {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    macro_rules! get_input {
-        () => {
-            &advent_of_code::read_file("inputs", 7)
-        };
-    }
-    #[test]
-    fn test_part_one() {
-        part_one(get_input!()).expect("First set of inputs resolves to a value.");
-    }
-
-    #[test]
-    fn test_part_two() {
-        //part_two(get_input!()).expect("Second set of inputs resolves to a value.");
-    }
+    advent_of_code::solution_tests!(Day);
 }
 
@@ -0,0 +1,146 @@
+use advent_of_code::helpers::err::TokenError;
+use advent_of_code::helpers::grid::{BitGrid, Direction, Grid};
+use advent_of_code::Solution;
+
+fn parse(input: &str) -> Result<Grid<u8>, TokenError> {
+    Grid::parse(input, |c| c.to_digit(10).map(|d| d as u8))
+}
+
+pub fn part_one(input: &str) -> Result<u128, TokenError> {
+    let grid = parse(input)?;
+    let mut visible = BitGrid::new(grid.width(), grid.height());
+    for edge in Direction::ALL {
+        visible |= &grid.visible_from(edge);
+    }
+    Ok(visible.count() as u128)
+}
+
+/// The scenic score of every tree. Walking each row and column both ways, a tree sees back to
+/// the last tree at least as high, so a table of where a tree of each of the ten heights was
+/// last blocked answers each tree in ten steps: O(w·h·10) in all, rather than a scan from every tree.
+/// Columns keep a table each and are walked a row at a time, so memory is read in order.
+fn scenic_scores(grid: &Grid<u8>) -> Grid<u32> {
+    let width = grid.width();
+    let mut scores = vec![1u32; grid.cells().len()];
+
+    for (row, scores) in grid.rows().zip(scores.chunks_mut(width)) {
+        let mut left = [0; 10];
+        let mut right = [0; 10];
+        for x in 0..width {
+            scores[x] *= look_back(&mut left, row[x], x);
+            let x = width - 1 - x;
+            scores[x] *= look_back(&mut right, row[x], width - 1 - x);
+        }
+    }
+
+    let mut up = vec![[0; 10]; width];
+    let mut down = vec![[0; 10]; width];
+    let height = grid.height();
+    for y in 0..height {
+        let row = &mut scores[y * width..(y + 1) * width];
+        for ((score, tree), up) in row.iter_mut().zip(grid.row(y)).zip(&mut up) {
+            *score *= look_back(up, *tree, y);
+        }
+        let y = height - 1 - y;
+        let row = &mut scores[y * width..(y + 1) * width];
+        for ((score, tree), down) in row.iter_mut().zip(grid.row(y)).zip(&mut down) {
+            *score *= look_back(down, *tree, height - 1 - y);
+        }
+    }
+
+    Grid::from_vec(width, scores).expect("the scores are as wide as the grid")
+}
+
+/// How far back the `i`-th tree of a line, of height `tree`, sees. `blockers[h]` is where the last
+/// tree at least `h` high was, so the tree also becomes the blocker of every height up to its own.
+#[inline]
+fn look_back(blockers: &mut [u32; 10], tree: u8, i: usize) -> u32 {
+    let i = i as u32;
+    let distance = i - blockers[tree as usize];
+    for (height, blocker) in blockers.iter_mut().enumerate() {
+        if height <= tree as usize {
+            *blocker = i;
+        }
+    }
+    distance
+}
+
+pub fn part_two(input: &str) -> Result<u32, TokenError> {
+    let grid = parse(input)?;
+    Ok(scenic_scores(&grid).cells().iter().copied().max().unwrap_or(0))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+    type Input<'a> = &'a str;
+    type PartOne = u128;
+    type PartTwo = u32;
+    type Error = TokenError;
+
+    fn part_one(input: &str) -> Result<u128, TokenError> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<u32, TokenError> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::bench::{self, BenchConfig};
+
+    advent_of_code::solution_tests!(Day);
+
+    /// Scenic scores the slow way, casting a ray from every tree in every direction.
+    fn scenic_scores_by_casting(grid: &Grid<u8>) -> Grid<u32> {
+        let mut scores = Grid::new(grid.width(), grid.height(), 1u32);
+        for (point, tree) in grid.iter() {
+            for direction in Direction::ALL {
+                scores[point] *= grid.cast(point, direction, |other| other >= tree).distance as u32;
+            }
+        }
+        scores
+    }
+
+    fn generate_forest(side: usize) -> String {
+        advent_of_code::generate::find(2022, 8).unwrap().generate(side, 0)
+    }
+
+    #[test]
+    fn test_scenic_scores_match_casting() {
+        for input in [advent_of_code::read_file("examples", 2022, 8), generate_forest(41)] {
+            let grid = parse(&input).unwrap();
+            assert_eq!(scenic_scores(&grid), scenic_scores_by_casting(&grid));
+        }
+    }
+
+    /// `cargo test --release y2022::day08 -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn bench_scenic_scores_on_a_large_forest() {
+        let forest = generate_forest(1000);
+        let config = BenchConfig {
+            samples: 20,
+            ..BenchConfig::default()
+        };
+        let tables = bench::bench(part_two, &forest, &config);
+        let casting = bench::bench(
+            |input| parse(input).map(|grid| scenic_scores_by_casting(&grid)),
+            &forest,
+            &config,
+        );
+        println!("last seen tables:");
+        tables.print();
+        println!("casting from every tree:");
+        casting.print();
+        println!(
+            "speedup: {:.1}x",
+            casting.median.as_secs_f64() / tables.median.as_secs_f64()
+        );
+    }
+}
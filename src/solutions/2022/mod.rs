@@ -0,0 +1,12 @@
+use advent_of_code::solution::Registry;
+
+days! {
+    day01 => "01.rs",
+    day02 => "02.rs",
+    day03 => "03.rs",
+    day04 => "04.rs",
+    day05 => "05.rs",
+    day06 => "06.rs",
+    day07 => "07.rs",
+    day08 => "08.rs",
+}
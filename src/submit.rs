@@ -0,0 +1,367 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::error;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::puzzle;
+
+/// Every answer sent by `cargo submit`, next to the recorded answers so it survives `cargo clean`.
+/// Git ignores it, as it holds wrong guesses and when they were sent.
+pub const DEFAULT_SUBMISSIONS_FILE: &str = "answers/submissions.jsonl";
+
+/// What Advent of Code made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Rejected without being checked, because the previous answer was too recent.
+    Throttled,
+    /// The part was already solved, so the answer was not checked.
+    AlreadySolved,
+    /// The page did not contain any of the known messages.
+    Unknown,
+}
+
+impl Outcome {
+    /// Reads the verdict and the imposed wait in seconds from the page returned for a submission.
+    pub fn parse(page: &str) -> (Self, Option<u64>) {
+        let message = message(page).to_lowercase();
+        let outcome = if message.contains("that's the right answer") {
+            Outcome::Correct
+        } else if message.contains("answer too recently") {
+            Outcome::Throttled
+        } else if message.contains("not the right answer") && message.contains("too high") {
+            Outcome::TooHigh
+        } else if message.contains("not the right answer") && message.contains("too low") {
+            Outcome::TooLow
+        } else if message.contains("not the right answer") {
+            Outcome::Wrong
+        } else if message.contains("solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown
+        };
+        (outcome, wait_seconds(&message))
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Outcome::Correct => "That's the right answer! ⭐",
+            Outcome::TooHigh => "That's not the right answer, it is too high.",
+            Outcome::TooLow => "That's not the right answer, it is too low.",
+            Outcome::Wrong => "That's not the right answer.",
+            Outcome::Throttled => "The previous answer was too recent, this one was not checked.",
+            Outcome::AlreadySolved => "This part is already solved, the answer was not checked.",
+            Outcome::Unknown => "Could not make sense of the response.",
+        })
+    }
+}
+
+/// The text of the page's `<article>`, which holds the verdict, on a single line.
+pub fn message(page: &str) -> String {
+    let body = match page.find("<article") {
+        Some(start) => {
+            let body = &page[start..];
+            &body[body.find('>').map_or(body.len(), |end| end + 1)..]
+        }
+        None => page,
+    };
+    let body = &body[..body.find("</article>").unwrap_or(body.len())];
+    puzzle::strip_tags(body)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Understands "you have 1m 23s left to wait" and "please wait 5 minutes before trying again".
+fn wait_seconds(message: &str) -> Option<u64> {
+    if let Some(start) = message.rfind("you have ") {
+        let rest = &message[start + "you have ".len()..];
+        let end = rest.find(" left to wait")?;
+        return rest[..end]
+            .split_whitespace()
+            .map(|token| {
+                let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+                let value: u64 = value.parse().ok()?;
+                match unit {
+                    "h" => Some(value * 3600),
+                    "m" => Some(value * 60),
+                    "s" => Some(value),
+                    _ => None,
+                }
+            })
+            .sum();
+    }
+    let start = message.find("wait ")?;
+    let mut words = message[start + "wait ".len()..].split_whitespace();
+    let count = match words.next()? {
+        "one" | "a" => 1,
+        count => count.parse().ok()?,
+    };
+    match words.next()?.trim_end_matches(['.', ',']) {
+        "minute" | "minutes" => Some(count * 60),
+        "second" | "seconds" => Some(count),
+        _ => None,
+    }
+}
+
+/// One line of the submissions file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub outcome: Outcome,
+    /// Milliseconds since the unix epoch.
+    pub timestamp: u64,
+    /// Seconds to wait before the next answer may be sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_s: Option<u64>,
+}
+
+impl Submission {
+    pub fn new(year: u16, day: u8, part: u8, answer: &Answer, page: &str) -> Self {
+        let (outcome, wait_s) = Outcome::parse(page);
+        Submission {
+            year,
+            day,
+            part,
+            answer: answer.clone(),
+            outcome,
+            timestamp: now(),
+            wait_s,
+        }
+    }
+}
+
+/// Milliseconds since the unix epoch, as recorded in `Submission::timestamp`.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// Why an answer is not sent, judging by earlier submissions.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Refusal {
+    Solved(Answer),
+    KnownWrong(Outcome),
+    AtLeast(Answer),
+    AtMost(Answer),
+    Wait(u64),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Refusal::Solved(answer) => {
+                f.write_fmt(format_args!("this part is already solved with {}", answer))
+            }
+            Refusal::KnownWrong(Outcome::TooHigh) => {
+                f.write_str("it was already rejected as too high")
+            }
+            Refusal::KnownWrong(Outcome::TooLow) => {
+                f.write_str("it was already rejected as too low")
+            }
+            Refusal::KnownWrong(_) => f.write_str("it was already rejected"),
+            Refusal::AtLeast(bound) => f.write_fmt(format_args!("{} was already too high", bound)),
+            Refusal::AtMost(bound) => f.write_fmt(format_args!("{} was already too low", bound)),
+            Refusal::Wait(seconds) => f.write_fmt(format_args!(
+                "Advent of Code asked to wait, {}s left",
+                seconds
+            )),
+        }
+    }
+}
+
+/// Decides against sending `answer` if it is known to be wrong or the previous answer is too recent.
+/// Integer answers are also checked against every recorded "too high" and "too low".
+pub fn check(
+    submissions: &[Submission],
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &Answer,
+    now_ms: u64,
+) -> Option<Refusal> {
+    let earlier: Vec<&Submission> = submissions
+        .iter()
+        .filter(|s| s.year == year && s.day == day && s.part == part)
+        .collect();
+
+    if let Some(solved) = earlier.iter().find(|s| s.outcome == Outcome::Correct) {
+        return Some(Refusal::Solved(solved.answer.clone()));
+    }
+    if let Some(known) = earlier
+        .iter()
+        .find(|s| s.answer.matches(answer) && s.outcome.is_wrong())
+    {
+        return Some(Refusal::KnownWrong(known.outcome));
+    }
+    if let Some(value) = answer.as_int() {
+        for s in &earlier {
+            match (s.outcome, s.answer.as_int()) {
+                (Outcome::TooHigh, Some(bound)) if value >= bound => {
+                    return Some(Refusal::AtLeast(s.answer.clone()))
+                }
+                (Outcome::TooLow, Some(bound)) if value <= bound => {
+                    return Some(Refusal::AtMost(s.answer.clone()))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let wait_until = earlier
+        .iter()
+        .filter_map(|s| Some(s.timestamp + s.wait_s? * 1000))
+        .max()?;
+    (wait_until > now_ms).then(|| Refusal::Wait((wait_until - now_ms).div_ceil(1000)))
+}
+
+pub fn default_path() -> PathBuf {
+    crate::input::project_dir().join(DEFAULT_SUBMISSIONS_FILE)
+}
+
+/// Reads every recorded submission, oldest first. A missing file means nothing was submitted yet.
+pub fn load(path: &Path) -> Result<Vec<Submission>, Box<dyn error::Error>> {
+    let file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    let mut submissions = vec![];
+    for line in io::BufReader::new(file).lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            submissions.push(serde_json::from_str(&line)?);
+        }
+    }
+    Ok(submissions)
+}
+
+pub fn append(path: &Path, submission: &Submission) -> Result<(), Box<dyn error::Error>> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    serde_json::to_writer(&mut file, submission)?;
+    writeln!(file)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(part: u8, answer: &str, outcome: Outcome, wait_s: Option<u64>) -> Submission {
+        Submission {
+            year: 2022,
+            day: 8,
+            part,
+            answer: Answer::parse(answer),
+            outcome,
+            timestamp: 10_000,
+            wait_s,
+        }
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        let page = |text: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", text);
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's the right answer!  You are <em>one gold star</em> closer."
+            )),
+            (Outcome::Correct, None)
+        );
+        assert_eq!(
+            Outcome::parse(&page("That's not the right answer; your answer is too high.  Please wait one minute before trying again. <a href=\"/2022/day/8\">[Return to Day 8]</a>")),
+            (Outcome::TooHigh, Some(60))
+        );
+        assert_eq!(
+            Outcome::parse(&page("That's not the right answer.  If you're stuck, please wait 5 minutes before trying again.")),
+            (Outcome::Wrong, Some(300))
+        );
+        assert_eq!(
+            Outcome::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.")),
+            (Outcome::Throttled, Some(83))
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            (Outcome::AlreadySolved, None)
+        );
+    }
+
+    #[test]
+    fn test_check_refuses_known_answers() {
+        let submissions = vec![
+            submission(1, "500", Outcome::TooHigh, Some(60)),
+            submission(1, "100", Outcome::TooLow, None),
+            submission(1, "abc", Outcome::Wrong, None),
+            submission(2, "42", Outcome::Correct, None),
+        ];
+        let check = |part, answer: &str, now_ms| {
+            check(&submissions, 2022, 8, part, &Answer::parse(answer), now_ms)
+        };
+
+        assert_eq!(check(2, "43", 0), Some(Refusal::Solved(Answer::Int(42))));
+        assert_eq!(
+            check(1, "abc", 0),
+            Some(Refusal::KnownWrong(Outcome::Wrong))
+        );
+        assert_eq!(check(1, "600", 0), Some(Refusal::AtLeast(Answer::Int(500))));
+        assert_eq!(
+            check(1, "100", 0),
+            Some(Refusal::KnownWrong(Outcome::TooLow))
+        );
+        assert_eq!(check(1, "99", 0), Some(Refusal::AtMost(Answer::Int(100))));
+        assert_eq!(
+            super::check(&submissions, 2022, 8, 1, &Answer::from("100"), 70_000),
+            None
+        );
+        assert_eq!(check(1, "300", 30_500), Some(Refusal::Wait(40)));
+        assert_eq!(check(1, "300", 70_000), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let path =
+            std::env::temp_dir().join(format!("aoc-submissions-test-{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let submissions = vec![
+            submission(1, "500", Outcome::TooHigh, Some(60)),
+            submission(1, "300", Outcome::Correct, None),
+        ];
+        for s in &submissions {
+            append(&path, s).unwrap();
+        }
+        let loaded = load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, submissions);
+    }
+}
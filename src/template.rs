@@ -0,0 +1,147 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::path::PathBuf;
+use std::{error, fmt, fs, io};
+
+/// Used by `cargo scaffold` unless `--template` names another file in `templates/`.
+pub const DEFAULT_TEMPLATE: &str = "string";
+
+#[derive(Debug)]
+pub enum TemplateError {
+    /// There is no `templates/<name>.rs`; holds the name and the templates there are.
+    NotFound(String, Vec<String>),
+    /// A `{{placeholder}}` that is not known, or that is never closed.
+    Placeholder(String),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TemplateError::NotFound(name, available) => f.write_fmt(format_args!(
+                "there is no template \"{}\", available are: {}",
+                name,
+                available.join(", ")
+            )),
+            TemplateError::Placeholder(placeholder) => f.write_fmt(format_args!(
+                "unknown placeholder {}, use {{{{day}}}}, {{{{day_padded}}}} or {{{{year}}}}",
+                placeholder
+            )),
+            TemplateError::Io(path, e) => {
+                f.write_fmt(format_args!("could not read \"{}\": {}", path.display(), e))
+            }
+        }
+    }
+}
+
+impl error::Error for TemplateError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            TemplateError::Io(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+pub fn dir() -> PathBuf {
+    crate::input::project_dir().join("templates")
+}
+
+/// Names of the templates in `templates/`, sorted.
+pub fn available() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            match path.extension() {
+                Some(ext) if ext == "rs" => Some(path.file_stem()?.to_string_lossy().to_string()),
+                _ => None,
+            }
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// Reads `templates/<name>.rs`.
+pub fn load(name: &str) -> Result<String, TemplateError> {
+    let path = dir().join(format!("{}.rs", name));
+    match fs::read_to_string(&path) {
+        Ok(template) => Ok(template),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            Err(TemplateError::NotFound(name.to_string(), available()))
+        }
+        Err(e) => Err(TemplateError::Io(path, e)),
+    }
+}
+
+/// Fills in `{{day}}`, `{{day_padded}}` and `{{year}}`. Only whole placeholders are replaced,
+/// so identifiers that merely contain "day" are left alone.
+pub fn render(template: &str, year: u16, day: u8) -> Result<String, TemplateError> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let end = match rest[start..].find("}}") {
+            Some(end) => start + end + 2,
+            None => {
+                return Err(TemplateError::Placeholder(
+                    rest[start..].lines().next().unwrap_or_default().to_string(),
+                ))
+            }
+        };
+        match rest[start + 2..end - 2].trim() {
+            "day" => out.push_str(&day.to_string()),
+            "day_padded" => out.push_str(&format!("{:02}", day)),
+            "year" => out.push_str(&year.to_string()),
+            _ => return Err(TemplateError::Placeholder(rest[start..end].to_string())),
+        }
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render(
+                "const DAY: u8 = {{day}}; // {{ year }}/{{day_padded}}.txt, DAY_COUNT",
+                2022,
+                8
+            )
+            .unwrap(),
+            "const DAY: u8 = 8; // 2022/08.txt, DAY_COUNT"
+        );
+        assert!(matches!(
+            render("{{month}}", 2022, 8),
+            Err(TemplateError::Placeholder(placeholder)) if placeholder == "{{month}}"
+        ));
+        assert!(render("{{day", 2022, 8).is_err());
+    }
+
+    #[test]
+    fn test_bundled_templates_render() {
+        let names = available();
+        for name in [DEFAULT_TEMPLATE, "bufread", "grid"] {
+            assert!(
+                names.iter().any(|n| n == name),
+                "templates/{}.rs is missing",
+                name
+            );
+            let module = render(&load(name).unwrap(), 2022, 8).unwrap();
+            assert!(module.contains("const YEAR: u16 = 2022;"));
+            assert!(module.contains("const DAY: u8 = 8;"));
+        }
+        assert!(
+            matches!(load("missing"), Err(TemplateError::NotFound(_, available)) if available == names)
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use advent_of_code::Solution;
+use std::error::Error;
+use std::io::BufRead;
+
+// reads the input line by line, see `advent_of_code::open_file_buffer("inputs", {{year}}, {{day}})`
+// to stream src/inputs/{{year}}/{{day_padded}}.txt without loading it first.
+pub fn part_one(input: impl BufRead) -> Result<u32, Box<dyn Error>> {
+    for line in input.lines() {
+        let line = line?;
+    }
+    todo!()
+}
+
+pub fn part_two(input: impl BufRead) -> Result<u32, Box<dyn Error>> {
+    for line in input.lines() {
+        let line = line?;
+    }
+    todo!()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    const YEAR: u16 = {{year}};
+    const DAY: u8 = {{day}};
+    type Input<'a> = &'a [u8];
+    type PartOne = u32;
+    type PartTwo = u32;
+    type Error = Box<dyn Error>;
+
+    fn part_one(input: &[u8]) -> Result<u32, Box<dyn Error>> {
+        part_one(input)
+    }
+
+    fn part_two(input: &[u8]) -> Result<u32, Box<dyn Error>> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::solution_tests!(Day);
+}
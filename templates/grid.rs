@@ -0,0 +1,44 @@
+use advent_of_code::helpers::err::TokenError;
+use advent_of_code::helpers::grid::Grid;
+use advent_of_code::Solution;
+
+/// The input as a grid of cells, indexed `grid[(x, y)]`.
+fn parse(input: &str) -> Result<Grid<u8>, TokenError> {
+    Grid::parse(input, |c| u8::try_from(c).ok())
+}
+
+pub fn part_one(input: &str) -> Result<u32, TokenError> {
+    let grid = parse(input)?;
+    todo!()
+}
+
+pub fn part_two(input: &str) -> Result<u32, TokenError> {
+    let grid = parse(input)?;
+    todo!()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    const YEAR: u16 = {{year}};
+    const DAY: u8 = {{day}};
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+    type Error = TokenError;
+
+    fn part_one(input: &str) -> Result<u32, TokenError> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<u32, TokenError> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::solution_tests!(Day);
+}
@@ -0,0 +1,36 @@
+use advent_of_code::helpers::err::TokenError;
+use advent_of_code::Solution;
+
+pub fn part_one(input: &str) -> Result<u32, TokenError> {
+    todo!()
+}
+
+pub fn part_two(input: &str) -> Result<u32, TokenError> {
+    todo!()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    const YEAR: u16 = {{year}};
+    const DAY: u8 = {{day}};
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+    type Error = TokenError;
+
+    fn part_one(input: &str) -> Result<u32, TokenError> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<u32, TokenError> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::solution_tests!(Day);
+}